@@ -7,7 +7,7 @@
 //
 // extern crate alloc;
 
-use miden::{component, component_storage, felt, Felt, StorageMap, Word};
+use miden::{assert_eq, component, component_storage, felt, tx, Felt, StorageMap, Word};
 
 /// Storage layout for the counter example.
 #[component_storage]
@@ -24,6 +24,22 @@ trait CounterContract {
     fn get_count(&self) -> Felt;
     /// Increments the counter value stored in the contract's storage map by one.
     fn increment_count(&mut self) -> Felt;
+    /// Increments the counter value stored in the contract's storage map by `amount`.
+    fn increment_count_by(&mut self, amount: Felt) -> Felt;
+    /// Decrements the counter value stored in the contract's storage map by one.
+    ///
+    /// Fails if the counter is zero instead of wrapping around the field modulus.
+    fn decrement_count(&mut self) -> Felt;
+    /// Sets the counter value stored in the contract's storage map to `value`.
+    ///
+    /// Only callable from a transaction script in a transaction without input notes, so
+    /// no note can overwrite the counter.
+    ///
+    /// This is not access control: on a counter account with a `NoAuth` component, anyone
+    /// can run the reset script against the account and set the counter to any value.
+    /// Install an authentication component (e.g. `AuthSingleSig`) on counters that must
+    /// only be reset by their owner.
+    fn set_count(&mut self, value: Felt);
 }
 
 #[component]
@@ -46,4 +62,39 @@ impl CounterContract for CounterContractStorage {
         self.count_map.set(key, new_value);
         new_value
     }
+
+    fn increment_count_by(&mut self, amount: Felt) -> Felt {
+        // Define the same fixed key
+        let key = Word::new([felt!(0), felt!(0), felt!(0), felt!(1)]);
+        // Read the current value
+        let current_value: Felt = self.count_map.get(key);
+        // Add the requested amount
+        let new_value = current_value + amount;
+        // Write the new value back to the storage map
+        self.count_map.set(key, new_value);
+        new_value
+    }
+
+    fn decrement_count(&mut self) -> Felt {
+        // Define the same fixed key
+        let key = Word::new([felt!(0), felt!(0), felt!(0), felt!(1)]);
+        // Read the current value
+        let current_value: Felt = self.count_map.get(key);
+        // Trap on underflow rather than wrapping around to a huge value
+        assert!(current_value.as_canonical_u64() > 0);
+        // Decrement the value by one
+        let new_value = current_value - felt!(1);
+        // Write the new value back to the storage map
+        self.count_map.set(key, new_value);
+        new_value
+    }
+
+    fn set_count(&mut self, value: Felt) {
+        // Any note calling this procedure would be an input note of the transaction
+        assert_eq(tx::get_num_input_notes(), felt!(0));
+        // Define the same fixed key
+        let key = Word::new([felt!(0), felt!(0), felt!(0), felt!(1)]);
+        // Overwrite the stored value
+        self.count_map.set(key, value);
+    }
 }
@@ -0,0 +1,20 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+// However, we could still use some standard library types while
+// remaining no-std compatible, if we uncommented the following lines:
+//
+// extern crate alloc;
+
+use miden::*;
+
+/// Native account of the script: exposes the `counter-contract` component methods gathered from the `counter-contract` package.
+#[account(counter_account::CounterContract)]
+pub struct CounterAccount;
+
+/// Decrements the counter of the executing account by one, failing if it is zero.
+#[tx_script]
+fn run(_arg: Word, account: &mut CounterAccount) {
+    account.decrement_count();
+}
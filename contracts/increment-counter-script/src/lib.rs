@@ -0,0 +1,24 @@
+// Do not link against libstd (i.e. anything defined in `std::`)
+#![no_std]
+#![feature(alloc_error_handler)]
+
+// However, we could still use some standard library types while
+// remaining no-std compatible, if we uncommented the following lines:
+//
+// extern crate alloc;
+
+use miden::*;
+
+/// Native account of the script: exposes the `counter-contract` component methods gathered from the `counter-contract` package.
+#[account(counter_account::CounterContract)]
+pub struct CounterAccount;
+
+/// Increments the counter of the executing account once per unit of the first element of
+/// the script argument, all within a single transaction.
+#[tx_script]
+fn run(arg: Word, account: &mut CounterAccount) {
+    let times = arg[0].as_canonical_u64();
+    for _ in 0..times {
+        account.increment_count();
+    }
+}
@@ -14,16 +14,19 @@ use miden::*;
 #[account(counter_account::CounterContract)]
 pub struct Wallet;
 
+/// Note storage: the amount to add to the counter.
 #[note]
-struct IncrementNote;
+struct IncrementNote {
+    amount: Felt,
+}
 
 #[note]
 impl IncrementNote {
     #[note_script]
     fn run(self, _arg: Word, account: &mut Wallet) {
         let initial_value = account.get_count();
-        account.increment_count();
-        let expected_value = initial_value + Felt::from_u32(1);
+        account.increment_count_by(self.amount);
+        let expected_value = initial_value + self.amount;
         let final_value = account.get_count();
         assert_eq(final_value, expected_value);
     }
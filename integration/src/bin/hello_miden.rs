@@ -0,0 +1,43 @@
+use integration::helpers::{
+    check_endpoint, create_basic_wallet_account, setup_client_with_retry, sync_with_retry,
+    AccountCreationConfig, ClientConfig, ClientSetup, DEFAULT_ENDPOINT_CHECK_TIMEOUT,
+    DEFAULT_SYNC_ATTEMPTS,
+};
+
+use anyhow::{Context, Result};
+
+/// Minimal end-to-end interaction: creates a wallet account and syncs with the network.
+#[tokio::main]
+async fn main() -> Result<()> {
+    // instantiate client
+    let config = ClientConfig::default();
+    let network = config.network.clone();
+    check_endpoint(&network.endpoint(), DEFAULT_ENDPOINT_CHECK_TIMEOUT).await?;
+    let (
+        ClientSetup {
+            mut client,
+            keystore,
+        },
+        sync_summary,
+    ) = setup_client_with_retry(config, DEFAULT_SYNC_ATTEMPTS).await?;
+    println!("Latest block: {}", sync_summary.block_num);
+
+    // create a basic wallet account
+    let account = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await
+    .context("Failed to create wallet account")?;
+
+    println!(
+        "Account ID: {}",
+        account.id().to_bech32(network.network_id())
+    );
+
+    let sync_summary = sync_with_retry(&mut client, DEFAULT_SYNC_ATTEMPTS, None).await?;
+    println!("Synced to block: {}", sync_summary.block_num);
+
+    Ok(())
+}
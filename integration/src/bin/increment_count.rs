@@ -1,99 +1,281 @@
 use integration::helpers::{
-    build_project_in_dir, counter_storage_slot, create_account_from_package,
-    create_basic_wallet_account, setup_client, AccountCreationConfig, ClientSetup,
-    COUNTER_STORAGE_KEY,
+    check_endpoint, consume_notes, counter_init_storage_data, counter_package,
+    create_account_from_package, create_basic_wallet_account, delete_keystore_and_store,
+    find_deployment, increment_note_package, midenscan_account_url, midenscan_tx_url,
+    parse_account_id, publish_note, read_deployment_manifest, record_deployment,
+    setup_client_with_retry, sync_with_retry, wait_for_tx, AccountCreationConfig, ClientConfig,
+    ClientSetup, CounterClient, DeploymentRecord, Network, WaitCancelled, WaitConfig, WalletAuth,
+    DEFAULT_ENDPOINT_CHECK_TIMEOUT, DEFAULT_SYNC_ATTEMPTS,
 };
 
 use anyhow::{Context, Result};
-use miden_client::{account::component::InitStorageData, transaction::TransactionRequestBuilder};
-use miden_standards::testing::note::NoteBuilder;
-use std::{path::Path, sync::Arc};
+use clap::Parser;
+use miden_client::{
+    account::{Account, AccountId},
+    keystore::{FilesystemKeyStore, KeyStoreError, Keystore},
+    transaction::TransactionStatus,
+    Client,
+};
+use std::path::Path;
+use tokio_util::sync::CancellationToken;
+
+/// Deployment manifest the counter is recorded in, relative to the integration crate.
+const DEPLOYMENT_PATH: &str = "../deployment.json";
+
+/// Name of the counter contract in the deployment manifest.
+const COUNTER_CONTRACT_NAME: &str = "counter-account";
+
+/// Deploys a counter account and increments it through increment notes.
+#[derive(Parser)]
+struct Args {
+    /// Number of increment transactions to submit in sequence.
+    #[arg(long, default_value_t = 1)]
+    count: u64,
+    /// Wipe the store and keystore and deploy a new counter instead of reusing the
+    /// accounts from previous runs.
+    #[arg(long)]
+    fresh: bool,
+}
+
+/// Returns the counter recorded in the deployment manifest, if it is still in the store.
+async fn stored_counter_account(
+    client: &Client<FilesystemKeyStore>,
+    network: &Network,
+) -> Result<Option<Account>> {
+    let deployment_path = Path::new(DEPLOYMENT_PATH);
+    if !deployment_path.exists() {
+        return Ok(None);
+    }
+    let manifest = read_deployment_manifest(deployment_path)?;
+    let Some(deployment) = find_deployment(&manifest, COUNTER_CONTRACT_NAME) else {
+        return Ok(None);
+    };
+    let counter_id = parse_account_id(&deployment.contract_address, network)
+        .context("Deployment file contains an invalid counter account address")?;
+    client
+        .get_account(counter_id)
+        .await
+        .context("Failed to look up counter account in store")
+}
+
+/// Returns a stored account other than `counter_id` that has a key in the keystore.
+async fn stored_sender_account(
+    client: &Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    counter_id: AccountId,
+) -> Result<Option<Account>> {
+    let headers = client
+        .get_account_headers()
+        .await
+        .context("Failed to list stored accounts")?;
+    for (header, _) in headers {
+        if header.id() == counter_id {
+            continue;
+        }
+        // Accounts without an auth key (e.g. `NoAuth` contracts) have no entry in the
+        // keystore index, which the lookup reports as a storage error.
+        match keystore.get_account_key_commitments(&header.id()).await {
+            Ok(_) => {}
+            Err(KeyStoreError::StorageError(_)) => continue,
+            Err(err) => return Err(err).context("Failed to read keys for stored account"),
+        }
+        let keys = keystore
+            .get_keys_for_account(&header.id())
+            .await
+            .context("Failed to read keys for stored account")?;
+        if !keys.is_empty() {
+            return client
+                .get_account(header.id())
+                .await
+                .context("Failed to load sender account from store");
+        }
+    }
+    Ok(None)
+}
+
+/// Exit code conventionally used by programs terminated by SIGINT.
+const SIGINT_EXIT_CODE: i32 = 130;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // instantiate client
-    let ClientSetup {
-        mut client,
-        keystore,
-    } = setup_client().await?;
+    let args = Args::parse();
+
+    // The first Ctrl-C cancels the wait and retry helpers so the binary stops between
+    // client operations instead of being killed in the middle of a store write. A second
+    // Ctrl-C exits immediately, e.g. while a transaction is still being proven.
+    let cancellation_token = CancellationToken::new();
+    tokio::spawn({
+        let cancellation_token = cancellation_token.clone();
+        async move {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                if cancellation_token.is_cancelled() {
+                    eprintln!("Second interrupt received, exiting");
+                    std::process::exit(SIGINT_EXIT_CODE);
+                }
+                println!("Interrupt received, stopping at the next wait (press Ctrl-C again to exit now)");
+                cancellation_token.cancel();
+            }
+        }
+    });
+    let wait_config = WaitConfig {
+        cancellation_token: Some(cancellation_token),
+        ..Default::default()
+    };
+
+    match run(args, &wait_config).await {
+        Err(err) if err.chain().any(|cause| cause.is::<WaitCancelled>()) => {
+            eprintln!("Interrupted: {err:#}");
+            std::process::exit(SIGINT_EXIT_CODE);
+        }
+        result => result,
+    }
+}
 
-    let sync_summary = client.sync_state().await?;
+async fn run(args: Args, wait_config: &WaitConfig) -> Result<()> {
+    let config = ClientConfig::default();
+    if args.fresh {
+        delete_keystore_and_store(&config)?;
+    }
+
+    // instantiate client
+    let network = config.network.clone();
+    check_endpoint(&network.endpoint(), DEFAULT_ENDPOINT_CHECK_TIMEOUT).await?;
+    let (
+        ClientSetup {
+            mut client,
+            keystore,
+        },
+        sync_summary,
+    ) = setup_client_with_retry(config, DEFAULT_SYNC_ATTEMPTS).await?;
     println!("Latest block: {}", sync_summary.block_num);
 
     // Build contracts
-    let counter_package = Arc::new(
-        build_project_in_dir(Path::new("../contracts/counter-account"), true)
-            .context("Failed to build counter account contract")?,
-    );
-    let note_package = Arc::new(
-        build_project_in_dir(Path::new("../contracts/increment-note"), true)
-            .context("Failed to build increment note contract")?,
-    );
+    let counter_package = counter_package()?;
+    let note_package = increment_note_package()?;
 
-    // Create the counter account with initial component storage.
-    let counter_storage_slot = counter_storage_slot()?;
-    let mut init_storage_data = InitStorageData::default();
-    init_storage_data
-        .insert_map_entry(counter_storage_slot, COUNTER_STORAGE_KEY, 0_u64)
-        .context("Failed to seed counter storage")?;
-    let counter_cfg = AccountCreationConfig {
-        init_storage_data,
-        ..Default::default()
+    // Reuse the counter from a previous run unless a fresh deployment was requested.
+    let stored_counter = if args.fresh {
+        None
+    } else {
+        stored_counter_account(&client, &network).await?
     };
+    let is_new_counter = stored_counter.is_none();
+    let counter_account = match stored_counter {
+        Some(account) => {
+            println!("Reusing counter account {}", account.id().to_hex());
+            account
+        }
+        None => {
+            // Create the counter account with initial component storage.
+            let counter_cfg = AccountCreationConfig {
+                init_storage_data: counter_init_storage_data(0)
+                    .context("Failed to seed counter storage")?,
+                ..Default::default()
+            };
 
-    // create counter account
-    let counter_account =
-        create_account_from_package(&mut client, counter_package.clone(), counter_cfg)
+            // Anyone may increment the counter through a note, so it has no auth key.
+            create_account_from_package(
+                &mut client,
+                &keystore,
+                counter_package.clone(),
+                WalletAuth::NoAuth,
+                counter_cfg,
+            )
             .await
-            .context("Failed to create counter account")?;
+            .context("Failed to create counter account")?
+        }
+    };
 
-    // Create a separate sender account using only the BasicWallet component
-    let sender_cfg = AccountCreationConfig::default();
-    let sender_account = create_basic_wallet_account(&mut client, keystore.clone(), sender_cfg)
-        .await
-        .context("Failed to create sender wallet account")?;
+    // Reuse the sender wallet from a previous run, or create one with only the
+    // BasicWallet component.
+    let sender_account =
+        match stored_sender_account(&client, &keystore, counter_account.id()).await? {
+            Some(account) => account,
+            None => {
+                let sender_cfg = AccountCreationConfig::default();
+                create_basic_wallet_account(&mut client, keystore.clone(), sender_cfg)
+                    .await
+                    .context("Failed to create sender wallet account")?
+            }
+        };
     println!("Sender account ID: {:?}", sender_account.id().to_hex());
 
-    // Build the increment note directly from the compiled package.
-    let counter_note = NoteBuilder::new(sender_account.id(), client.rng())
-        .package((*note_package).clone())
-        .tag(0)
-        .build()
-        .context("Failed to create counter note from package")?;
-    println!("Counter note hash: {:?}", counter_note.id().to_hex());
-
-    // build and submit transaction to publish note
-    let note_publish_request = TransactionRequestBuilder::new()
-        .own_output_notes(vec![counter_note.clone()])
-        .build()
-        .context("Failed to build note publish transaction request")?;
-
-    let note_publish_tx_id = client
-        .submit_new_transaction(sender_account.id(), note_publish_request)
-        .await
-        .context("Failed to create note publish transaction")?;
+    let counter = CounterClient::new(counter_account.id(), note_package)?;
+    let start_value = counter.counter_value(&client).await?;
+    println!("Counter value before increments: {start_value}");
 
-    client
-        .sync_state()
-        .await
-        .context("Failed to sync state after publishing note")?;
+    for iteration in 1..=args.count {
+        // Build the increment note directly from the compiled package.
+        let counter_note = counter
+            .increment_note(sender_account.id(), client.rng(), 1)
+            .context("Failed to create counter note from package")?;
+        println!("Counter note hash: {:?}", counter_note.id().to_hex());
 
-    println!(
-        "Note publish transaction ID: {:?}",
-        note_publish_tx_id.to_hex()
-    );
+        // Submit a transaction publishing the note and wait until it is committed.
+        let note_publish_tx_id =
+            publish_note(&mut client, sender_account.id(), counter_note.clone()).await?;
+        println!(
+            "Note publish transaction ID: {:?}",
+            note_publish_tx_id.to_hex()
+        );
 
-    let consume_note_request = TransactionRequestBuilder::new()
-        .input_notes([(counter_note.clone(), None)])
-        .build()
-        .context("Failed to build consume note transaction request")?;
+        let note_publish_record = wait_for_tx(&mut client, note_publish_tx_id, wait_config)
+            .await
+            .context("Note publish transaction was not committed")?;
+        println!("Note publish transaction {}", note_publish_record.status);
 
-    let consume_tx_id = client
-        .submit_new_transaction(counter_account.id(), consume_note_request)
-        .await
-        .context("Failed to create consume note transaction")?;
+        let consume_tx_id = consume_notes(
+            &mut client,
+            counter_account.id(),
+            vec![(counter_note.clone(), None)],
+        )
+        .await?;
+
+        println!("Consume transaction ID: {:?}", consume_tx_id.to_hex());
+        if let Some(url) = midenscan_tx_url(&network.network_id(), consume_tx_id) {
+            println!("View transaction on MidenScan: {url}");
+        }
 
-    println!("Consume transaction ID: {:?}", consume_tx_id.to_hex());
+        // Wait for the consume transaction itself: the local store already reflects it
+        // as soon as it is submitted, so the counter value alone proves nothing.
+        let consume_record = wait_for_tx(&mut client, consume_tx_id, wait_config)
+            .await
+            .context("Increment transaction was not committed")?;
+        let TransactionStatus::Committed { block_number, .. } = consume_record.status else {
+            unreachable!("wait_for_tx only returns committed transactions");
+        };
+        println!(
+            "Increment {iteration}/{} committed in block {block_number}",
+            args.count
+        );
+
+        // A new counter account is deployed on chain by its first transaction.
+        if is_new_counter && iteration == 1 {
+            let deployment = DeploymentRecord::new(
+                COUNTER_CONTRACT_NAME.to_string(),
+                &counter_account,
+                &network,
+                consume_tx_id,
+                block_number,
+            );
+            record_deployment(Path::new(DEPLOYMENT_PATH), deployment)?;
+            println!("Deployment info written to {DEPLOYMENT_PATH}");
+            if let Some(url) = midenscan_account_url(&network.network_id(), counter_account.id()) {
+                println!("View counter account on MidenScan: {url}");
+            }
+        }
+    }
+
+    sync_with_retry(
+        &mut client,
+        DEFAULT_SYNC_ATTEMPTS,
+        wait_config.cancellation_token.as_ref(),
+    )
+    .await?;
+    println!(
+        "Counter value after increments: {}",
+        counter.counter_value(&client).await?
+    );
 
     Ok(())
 }
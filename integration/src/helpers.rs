@@ -1,24 +1,52 @@
 //! Common helper functions for scripts and tests
 
-use std::{path::Path, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
+};
 
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use cargo_miden::run;
 use miden_client::{
     account::{
         component::{BasicWallet, InitStorageData, NoAuth},
-        Account, AccountBuilder, AccountComponent, AccountType, StorageSlotName,
+        Account, AccountBuilder, AccountComponent, AccountFile, AccountId, AccountType,
+        StorageMapKey, StorageSlotName,
     },
+    address::NetworkId,
+    asset::{Asset, FungibleAsset},
     auth::{AuthSchemeId, AuthSecretKey, AuthSingleSig},
+    block::BlockNumber,
     builder::ClientBuilder,
-    keystore::{FilesystemKeyStore, Keystore},
-    rpc::{Endpoint, GrpcClient},
+    keystore::{FilesystemKeyStore, KeyStoreError, Keystore},
+    note::{
+        Note, NoteAssets, NoteAttachments, NoteConsumptionStatus, NoteDetails, NoteFile, NoteId,
+        NoteTag, NoteType,
+    },
+    rpc::{Endpoint, GrpcClient, GrpcError, RpcError},
+    store::TransactionFilter,
+    sync::SyncSummary,
+    transaction::{
+        ExecutedTransaction, NoteArgs, SwapTransactionData, TransactionId, TransactionRecord,
+        TransactionRequest, TransactionRequestBuilder, TransactionScript, TransactionStatus,
+    },
     utils::Deserializable,
-    Client, Felt, Word,
+    Client, ClientError, Felt, Word,
 };
 use miden_client_sqlite_store::ClientBuilderSqliteExt;
 use miden_mast_package::Package;
-use rand::RngCore;
+use miden_standards::{
+    note::{NetworkAccountTarget, NoteExecutionHint, P2idNote, SwapNote},
+    testing::note::NoteBuilder,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 
 /// Test setup configuration containing initialized client and keystore
 pub struct ClientSetup {
@@ -28,8 +56,155 @@ pub struct ClientSetup {
     pub keystore: Arc<FilesystemKeyStore>,
 }
 
+/// Backing storage for the client store
+pub enum StoreKind {
+    /// SQLite database file at the given path, persisted between runs.
+    Sqlite(PathBuf),
+    /// Private in-memory SQLite database, discarded when the client is dropped.
+    InMemory,
+}
+
+impl StoreKind {
+    /// Returns the database path handed to the SQLite store.
+    ///
+    /// In-memory stores use a uniquely named shared-cache database so that all pooled
+    /// connections of one client see the same data while separate clients stay isolated.
+    pub(crate) fn database_path(&self) -> PathBuf {
+        static NEXT_IN_MEMORY_ID: AtomicUsize = AtomicUsize::new(0);
+
+        match self {
+            StoreKind::Sqlite(path) => path.clone(),
+            StoreKind::InMemory => {
+                let id = NEXT_IN_MEMORY_ID.fetch_add(1, Ordering::Relaxed);
+                PathBuf::from(format!(
+                    "file:miden-store-{}-{id}?mode=memory&cache=shared",
+                    std::process::id()
+                ))
+            }
+        }
+    }
+}
+
+/// Miden network a client connects to
+///
+/// Yields both the RPC `Endpoint` and the `NetworkId` used for bech32 account IDs, so the
+/// displayed addresses always match the node the client talks to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Network {
+    /// The public Miden testnet.
+    Testnet,
+    /// The public Miden devnet.
+    Devnet,
+    /// A node running on `localhost` with the default port.
+    Localhost,
+    /// Any other node, identified by its endpoint.
+    Custom(Endpoint),
+}
+
+impl Network {
+    /// Returns the RPC endpoint of the network.
+    pub fn endpoint(&self) -> Endpoint {
+        match self {
+            Network::Testnet => Endpoint::testnet(),
+            Network::Devnet => Endpoint::devnet(),
+            Network::Localhost => Endpoint::localhost(),
+            Network::Custom(endpoint) => endpoint.clone(),
+        }
+    }
+
+    /// Returns the network ID used when formatting bech32 account IDs.
+    pub fn network_id(&self) -> NetworkId {
+        self.endpoint().to_network_id()
+    }
+}
+
+impl std::fmt::Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Network::Testnet => f.write_str("testnet"),
+            Network::Devnet => f.write_str("devnet"),
+            Network::Localhost => f.write_str("localhost"),
+            Network::Custom(endpoint) => write!(f, "{endpoint}"),
+        }
+    }
+}
+
+/// Parses a bech32 account ID and checks that it belongs to `expected_network`
+///
+/// # Arguments
+/// * `s` - The bech32-encoded account ID, e.g. from an environment variable
+/// * `expected_network` - The network the ID must be encoded for
+///
+/// # Returns
+/// The decoded `AccountId`
+///
+/// # Errors
+/// Returns an error naming the offending string if it is not a valid bech32 account ID,
+/// or if it is encoded for a different network than `expected_network`
+pub fn parse_account_id(s: &str, expected_network: &Network) -> Result<AccountId> {
+    let (network_id, account_id) =
+        AccountId::from_bech32(s).with_context(|| format!("Invalid bech32 account ID '{s}'"))?;
+
+    let expected_network_id = expected_network.network_id();
+    if network_id != expected_network_id {
+        bail!(
+            "Account ID '{s}' is for network '{network_id}', expected '{expected_network_id}' ({expected_network})"
+        );
+    }
+    Ok(account_id)
+}
+
+/// Returns the MidenScan base URL for `network`, if it has a public explorer.
+fn midenscan_base_url(network: &NetworkId) -> Option<&'static str> {
+    match network {
+        NetworkId::Mainnet => Some("https://midenscan.com"),
+        NetworkId::Testnet => Some("https://testnet.midenscan.com"),
+        NetworkId::Devnet => Some("https://devnet.midenscan.com"),
+        NetworkId::Custom(_) => None,
+    }
+}
+
+/// Returns the MidenScan page of a transaction on `network`
+///
+/// Returns `None` for custom networks (including a local node), which have no public
+/// explorer. Use `Network::network_id` to get the ID of the network a client talks to.
+pub fn midenscan_tx_url(network: &NetworkId, tx_id: TransactionId) -> Option<String> {
+    midenscan_base_url(network).map(|base| format!("{base}/tx/{}", tx_id.to_hex()))
+}
+
+/// Returns the MidenScan page of an account on `network`
+///
+/// Returns `None` for custom networks (including a local node), which have no public
+/// explorer. Use `Network::network_id` to get the ID of the network a client talks to.
+pub fn midenscan_account_url(network: &NetworkId, account_id: AccountId) -> Option<String> {
+    midenscan_base_url(network).map(|base| format!("{base}/account/{}", account_id.to_hex()))
+}
+
+/// Configuration for the network, keystore and store used by the client
+pub struct ClientConfig {
+    /// Network of the Miden node to connect to.
+    pub network: Network,
+    /// Directory holding the filesystem keystore.
+    pub keystore_path: PathBuf,
+    /// Store backing the client state.
+    pub store: StoreKind,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            network: Network::Testnet,
+            keystore_path: PathBuf::from("../keystore"),
+            store: StoreKind::Sqlite(PathBuf::from("../store.sqlite3")),
+        }
+    }
+}
+
 /// Initializes test infrastructure with client and keystore
 ///
+/// Uses the default `ClientConfig`: testnet with the keystore and store next to the
+/// workspace root.
+///
 /// # Returns
 /// A `ClientSetup` containing the initialized client and keystore
 ///
@@ -37,29 +212,302 @@ pub struct ClientSetup {
 /// Returns an error if RPC connection fails, keystore initialization fails,
 /// or client building fails
 pub async fn setup_client() -> Result<ClientSetup> {
-    // Initialize RPC connection
-    let endpoint = Endpoint::testnet();
-    let timeout_ms = 10_000;
-    let rpc_client = Arc::new(GrpcClient::new(&endpoint, timeout_ms));
+    setup_client_with_config(ClientConfig::default()).await
+}
 
+/// Initializes a client and keystore as described by `config`
+///
+/// With `StoreKind::InMemory` nothing is written for the store, so tests using it only
+/// need a unique `keystore_path` to run in parallel.
+///
+/// # Arguments
+/// * `config` - Network, keystore location and store kind
+///
+/// # Returns
+/// A `ClientSetup` containing the initialized client and keystore
+///
+/// # Errors
+/// Returns an error if RPC connection fails, keystore initialization fails,
+/// or client building fails
+pub async fn setup_client_with_config(config: ClientConfig) -> Result<ClientSetup> {
     // Initialize keystore
-    let keystore_path = std::path::PathBuf::from("../keystore");
+    let keystore = Arc::new(
+        FilesystemKeyStore::new(config.keystore_path).context("Failed to initialize keystore")?,
+    );
+
+    let client = build_client(
+        &config.network.endpoint(),
+        config.store.database_path(),
+        keystore.clone(),
+    )
+    .await?;
+
+    Ok(ClientSetup { client, keystore })
+}
 
-    let keystore =
-        Arc::new(FilesystemKeyStore::new(keystore_path).context("Failed to initialize keystore")?);
+/// Initializes a client as described by `config` and syncs it with the node
+///
+/// Building the client does not contact the node, so the first sync is the first RPC call;
+/// it is retried with backoff on transient RPC errors and the client is only returned
+/// once it has synced.
+///
+/// # Arguments
+/// * `config` - Network, keystore location and store kind
+/// * `max_attempts` - Maximum number of attempts for the initial sync
+///
+/// # Returns
+/// The `ClientSetup` and the `SyncSummary` of the initial sync
+///
+/// # Errors
+/// Returns an error if client setup fails, or if the initial sync fails with a
+/// non-transient error or after `max_attempts` attempts
+pub async fn setup_client_with_retry(
+    config: ClientConfig,
+    max_attempts: u32,
+) -> Result<(ClientSetup, SyncSummary)> {
+    let mut setup = setup_client_with_config(config).await?;
+    let sync_summary = sync_with_retry(&mut setup.client, max_attempts, None)
+        .await
+        .context("Failed to complete the initial sync")?;
+    Ok((setup, sync_summary))
+}
 
-    let store_path = std::path::PathBuf::from("../store.sqlite3");
+/// Builds a client talking to `endpoint` on top of the SQLite store at `database_path`.
+async fn build_client(
+    endpoint: &Endpoint,
+    database_path: PathBuf,
+    keystore: Arc<FilesystemKeyStore>,
+) -> Result<Client<FilesystemKeyStore>> {
+    // Initialize RPC connection
+    let timeout_ms = 10_000;
+    let rpc_client = Arc::new(GrpcClient::new(endpoint, timeout_ms));
 
-    let client = ClientBuilder::new()
+    ClientBuilder::new()
         .rpc(rpc_client)
-        .sqlite_store(store_path)
-        .authenticator(keystore.clone())
+        .sqlite_store(database_path)
+        .authenticator(keystore)
         .in_debug_mode(true.into())
         .build()
         .await
-        .context("Failed to build Miden client")?;
+        .context("Failed to build Miden client")
+}
 
-    Ok(ClientSetup { client, keystore })
+/// Initializes a client without a keystore, for reading chain and store state only
+///
+/// `config.keystore_path` is ignored and no keystore directory is created. The client can
+/// sync and query accounts and notes, but executing transactions that need signatures
+/// fails.
+///
+/// # Arguments
+/// * `config` - Network and store kind; the keystore path is not used
+///
+/// # Returns
+/// The configured Miden client instance
+///
+/// # Errors
+/// Returns an error if client building fails
+pub async fn setup_read_only_client(config: ClientConfig) -> Result<Client<FilesystemKeyStore>> {
+    // Initialize RPC connection
+    let timeout_ms = 10_000;
+    let rpc_client = Arc::new(GrpcClient::new(&config.network.endpoint(), timeout_ms));
+
+    ClientBuilder::new()
+        .rpc(rpc_client)
+        .sqlite_store(config.store.database_path())
+        .in_debug_mode(true.into())
+        .build()
+        .await
+        .context("Failed to build read-only Miden client")
+}
+
+/// A client that can fail over to fallback RPC endpoints
+///
+/// The wrapper keeps the store location and keystore of the client it was built with, so
+/// switching endpoints rebuilds only the RPC connection: tracked accounts, notes and keys
+/// are kept.
+pub struct ManagedClient {
+    client: Client<FilesystemKeyStore>,
+    keystore: Arc<FilesystemKeyStore>,
+    database_path: PathBuf,
+    endpoints: Vec<Endpoint>,
+    active_endpoint: usize,
+}
+
+impl ManagedClient {
+    /// Initializes a client as described by `config`
+    ///
+    /// # Arguments
+    /// * `config` - Network, keystore location and store kind; the network endpoint is
+    ///   the primary endpoint
+    /// * `fallback_endpoints` - Endpoints to switch to, in order, when the active one fails
+    ///
+    /// # Errors
+    /// Returns an error if keystore initialization or client building fails
+    pub async fn new(config: ClientConfig, fallback_endpoints: Vec<Endpoint>) -> Result<Self> {
+        let keystore = Arc::new(
+            FilesystemKeyStore::new(config.keystore_path)
+                .context("Failed to initialize keystore")?,
+        );
+        let database_path = config.store.database_path();
+
+        let mut endpoints = vec![config.network.endpoint()];
+        endpoints.extend(fallback_endpoints);
+
+        let client = build_client(&endpoints[0], database_path.clone(), keystore.clone()).await?;
+        Ok(Self {
+            client,
+            keystore,
+            database_path,
+            endpoints,
+            active_endpoint: 0,
+        })
+    }
+
+    /// Returns the wrapped client.
+    pub fn client(&self) -> &Client<FilesystemKeyStore> {
+        &self.client
+    }
+
+    /// Returns the wrapped client mutably, e.g. to pass it to the other helpers.
+    pub fn client_mut(&mut self) -> &mut Client<FilesystemKeyStore> {
+        &mut self.client
+    }
+
+    /// Returns the keystore shared by every client this wrapper builds.
+    pub fn keystore(&self) -> Arc<FilesystemKeyStore> {
+        self.keystore.clone()
+    }
+
+    /// Returns the endpoint the client is currently connected to.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoints[self.active_endpoint]
+    }
+
+    /// Rebuilds the client against the next endpoint, wrapping around after the last one
+    ///
+    /// # Returns
+    /// The endpoint the client is now connected to
+    ///
+    /// # Errors
+    /// Returns an error if the new client cannot be built; the current client is kept
+    pub async fn failover(&mut self) -> Result<&Endpoint> {
+        let next_endpoint = (self.active_endpoint + 1) % self.endpoints.len();
+        // Build the new client before dropping the old one, so an in-memory store stays
+        // alive through the switch.
+        let client = build_client(
+            &self.endpoints[next_endpoint],
+            self.database_path.clone(),
+            self.keystore.clone(),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to reconnect to fallback endpoint {}",
+                self.endpoints[next_endpoint]
+            )
+        })?;
+
+        println!(
+            "Switching from endpoint {} to {}",
+            self.endpoint(),
+            self.endpoints[next_endpoint]
+        );
+        self.client = client;
+        self.active_endpoint = next_endpoint;
+        Ok(self.endpoint())
+    }
+
+    /// Syncs the client state, failing over to the next endpoint when one stays unreachable
+    ///
+    /// Each endpoint is tried with [`sync_with_retry`] before switching, and every endpoint
+    /// is tried at most once.
+    ///
+    /// # Arguments
+    /// * `max_attempts` - Maximum number of sync attempts per endpoint
+    ///
+    /// # Returns
+    /// The summary of the successful sync
+    ///
+    /// # Errors
+    /// Returns an error if the sync fails with a non-transient error, or if every endpoint
+    /// remains unreachable
+    pub async fn sync_with_retry(&mut self, max_attempts: u32) -> Result<SyncSummary> {
+        let mut remaining_endpoints = self.endpoints.len();
+        loop {
+            let err = match sync_with_retry(&mut self.client, max_attempts, None).await {
+                Ok(summary) => return Ok(summary),
+                Err(err) => err,
+            };
+            remaining_endpoints -= 1;
+            let transient = err
+                .downcast_ref::<ClientError>()
+                .is_some_and(is_transient_rpc_error);
+            if !transient || remaining_endpoints == 0 {
+                return Err(err);
+            }
+            println!("Endpoint {} is unreachable: {err:#}", self.endpoint());
+            self.failover().await?;
+        }
+    }
+}
+
+/// Timeout the binaries use when checking that the RPC endpoint is reachable.
+pub const DEFAULT_ENDPOINT_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Checks that the node at `endpoint` answers a status request
+///
+/// Client construction is lazy, so an unreachable node otherwise only shows up as an
+/// opaque error from the first sync. Calling this up front fails fast with the endpoint
+/// in the message.
+///
+/// # Arguments
+/// * `endpoint` - The RPC endpoint to check
+/// * `timeout` - How long to wait for the status response
+///
+/// # Errors
+/// Returns an error naming the endpoint if it does not answer within `timeout`
+pub async fn check_endpoint(endpoint: &Endpoint, timeout: Duration) -> Result<()> {
+    let timeout_ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+    let rpc_client = GrpcClient::new(endpoint, timeout_ms);
+
+    match tokio::time::timeout(timeout, rpc_client.get_status_unversioned()).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(err)) => Err(err).context(format!("RPC unreachable at {endpoint}")),
+        Err(_) => bail!("RPC unreachable at {endpoint}: no response within {timeout:?}"),
+    }
+}
+
+/// Deletes the keystore directory and store database at the locations given by `config`
+///
+/// Only the configured keystore directory and store file are removed, so clients
+/// using distinct locations (e.g. parallel tests) do not affect each other.
+/// Locations that do not exist are ignored, as are in-memory stores.
+///
+/// # Arguments
+/// * `config` - Keystore and store locations to delete
+///
+/// # Errors
+/// Returns an error if an existing keystore or store cannot be removed
+pub fn delete_keystore_and_store(config: &ClientConfig) -> Result<()> {
+    if let StoreKind::Sqlite(store_path) = &config.store {
+        match std::fs::remove_file(store_path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                return Err(err).context(format!(
+                    "Failed to delete store at {}",
+                    store_path.display()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    match std::fs::remove_dir_all(&config.keystore_path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err).context(format!(
+            "Failed to delete keystore at {}",
+            config.keystore_path.display()
+        )),
+        _ => Ok(()),
+    }
 }
 
 /// Builds a Miden project in the specified directory
@@ -107,6 +555,110 @@ pub fn build_project_in_dir(dir: &Path, release: bool) -> Result<Package> {
     Package::read_from_bytes(&package_bytes).context("Failed to deserialize package from bytes")
 }
 
+/// Compiled packages keyed on canonical project directory and release flag.
+type PackageCache = Mutex<HashMap<(PathBuf, bool), Arc<Package>>>;
+
+/// Builds a Miden project in the specified directory, reusing earlier builds
+///
+/// Compiled packages are cached per process, keyed on the canonical project directory
+/// and build profile, so repeated calls (e.g. from several tests in one test binary)
+/// only invoke `cargo miden build` once.
+///
+/// # Arguments
+/// * `dir` - Path to the directory containing the Cargo.toml
+/// * `release` - Whether to build in release mode
+///
+/// # Returns
+/// The compiled `Package`, shared with all other callers that requested the same build
+///
+/// # Errors
+/// Returns an error if the directory cannot be resolved or if the build fails
+pub fn build_project_in_dir_cached(dir: &Path, release: bool) -> Result<Arc<Package>> {
+    static PACKAGE_CACHE: OnceLock<PackageCache> = OnceLock::new();
+
+    let dir = dir.canonicalize().context(format!(
+        "Failed to resolve project directory {}",
+        dir.display()
+    ))?;
+    let cache = PACKAGE_CACHE.get_or_init(Default::default);
+
+    // Holding the lock for the duration of the build also prevents concurrent builds
+    // of the same project from racing on the shared target directory.
+    let mut cache = cache
+        .lock()
+        .map_err(|_| anyhow!("Package cache lock was poisoned"))?;
+    if let Some(package) = cache.get(&(dir.clone(), release)) {
+        return Ok(package.clone());
+    }
+
+    let package = Arc::new(build_project_in_dir(&dir, release)?);
+    cache.insert((dir, release), package.clone());
+    Ok(package)
+}
+
+/// Directory of the counter account contract, relative to the integration crate.
+pub const COUNTER_ACCOUNT_PATH: &str = "../contracts/counter-account";
+
+/// Builds the counter account contract, reusing earlier builds in this process
+///
+/// # Returns
+/// The compiled counter account `Package`
+///
+/// # Errors
+/// Returns an error if the counter account contract fails to build
+pub fn counter_package() -> Result<Arc<Package>> {
+    build_project_in_dir_cached(Path::new(COUNTER_ACCOUNT_PATH), true)
+        .context("Failed to build counter account contract")
+}
+
+/// Directory of the increment note contract, relative to the integration crate.
+pub const INCREMENT_NOTE_PATH: &str = "../contracts/increment-note";
+
+/// Builds the increment note contract, reusing earlier builds in this process
+///
+/// # Errors
+/// Returns an error if the increment note contract fails to build
+pub fn increment_note_package() -> Result<Arc<Package>> {
+    build_project_in_dir_cached(Path::new(INCREMENT_NOTE_PATH), true)
+        .context("Failed to build increment note contract")
+}
+
+/// Directory of the reset counter transaction script, relative to the integration crate.
+pub const RESET_COUNTER_SCRIPT_PATH: &str = "../contracts/reset-counter-script";
+
+/// Builds the reset counter transaction script, reusing earlier builds in this process
+///
+/// # Errors
+/// Returns an error if the reset counter script fails to build
+pub fn reset_counter_script_package() -> Result<Arc<Package>> {
+    build_project_in_dir_cached(Path::new(RESET_COUNTER_SCRIPT_PATH), true)
+        .context("Failed to build reset counter script")
+}
+
+/// Directory of the batch increment transaction script, relative to the integration crate.
+pub const INCREMENT_COUNTER_SCRIPT_PATH: &str = "../contracts/increment-counter-script";
+
+/// Builds the batch increment transaction script, reusing earlier builds in this process
+///
+/// # Errors
+/// Returns an error if the batch increment script fails to build
+pub fn increment_counter_script_package() -> Result<Arc<Package>> {
+    build_project_in_dir_cached(Path::new(INCREMENT_COUNTER_SCRIPT_PATH), true)
+        .context("Failed to build batch increment script")
+}
+
+/// Directory of the decrement counter transaction script, relative to the integration crate.
+pub const DECREMENT_COUNTER_SCRIPT_PATH: &str = "../contracts/decrement-counter-script";
+
+/// Builds the decrement counter transaction script, reusing earlier builds in this process
+///
+/// # Errors
+/// Returns an error if the decrement counter script fails to build
+pub fn decrement_counter_script_package() -> Result<Arc<Package>> {
+    build_project_in_dir_cached(Path::new(DECREMENT_COUNTER_SCRIPT_PATH), true)
+        .context("Failed to build decrement counter script")
+}
+
 /// The fixed key used by the counter contract to store the counter value.
 pub const COUNTER_STORAGE_KEY: Word = Word::new([Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ONE]);
 
@@ -119,6 +671,18 @@ pub fn counter_storage_slot() -> Result<StorageSlotName> {
         .context("invalid counter storage slot name")
 }
 
+/// Returns the initial storage of a counter account starting at `initial`.
+///
+/// # Errors
+/// Returns an error if `initial` is not a valid field element.
+pub fn counter_init_storage_data(initial: u64) -> Result<InitStorageData> {
+    let mut init_storage_data = InitStorageData::default();
+    init_storage_data
+        .insert_map_entry(counter_storage_slot()?, COUNTER_STORAGE_KEY, initial)
+        .context("Failed to seed counter storage")?;
+    Ok(init_storage_data)
+}
+
 /// Configuration for creating an account with a custom component
 pub struct AccountCreationConfig {
     /// The account type to create. In protocol v0.15 this also encodes the
@@ -126,6 +690,15 @@ pub struct AccountCreationConfig {
     pub account_type: AccountType,
     /// Initial component storage data keyed by storage slot schema.
     pub init_storage_data: InitStorageData,
+    /// Seed for the account ID. When set, the account ID is reproducible; otherwise it is
+    /// drawn from the client RNG. The seed is public: it is kept in the store, written to
+    /// account files and sent with the account's first transaction.
+    pub init_seed: Option<[u8; 32]>,
+    /// Seed for the authentication key of wallets; otherwise the key is drawn from the
+    /// client RNG. Anyone knowing the seed can rebuild the key, so seeded keys are only
+    /// meant for tests. A wallet's ID also commits to its key, so a reproducible wallet
+    /// ID needs both seeds.
+    pub key_seed: Option<[u8; 32]>,
 }
 
 impl Default for AccountCreationConfig {
@@ -133,60 +706,143 @@ impl Default for AccountCreationConfig {
         Self {
             account_type: AccountType::Public,
             init_storage_data: InitStorageData::default(),
+            init_seed: None,
+            key_seed: None,
         }
     }
 }
 
+/// Draws a fresh 32-byte seed from the client RNG.
+fn random_seed(client: &mut Client<FilesystemKeyStore>) -> [u8; 32] {
+    let mut seed = [0_u8; 32];
+    client.rng().fill_bytes(&mut seed);
+    seed
+}
+
+/// Returns the account with `account_id` if it is already tracked by the client store.
+///
+/// Creating an account from a fixed `init_seed` yields the same ID on every run, so the
+/// creation helpers reuse the stored account instead of failing to add it twice.
+async fn find_stored_account(
+    client: &Client<FilesystemKeyStore>,
+    account_id: AccountId,
+) -> Result<Option<Account>> {
+    let existing = client
+        .get_account(account_id)
+        .await
+        .context("Failed to look up account in client store")?;
+    if existing.is_some() {
+        println!("Account {account_id} already in store, reusing it");
+    }
+    Ok(existing)
+}
+
+/// Creates an account from an arbitrary account component
+///
+/// This is the generic creation path: any `AccountComponent`, whether compiled from a
+/// package or built in Rust, can be turned into an account this way. Components whose
+/// procedures must only be called by the account owner need `WalletAuth::SingleSig`;
+/// `WalletAuth::NoAuth` lets anyone execute transactions against the account.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the authentication key, if `auth` has one
+/// * `component` - The account component to install
+/// * `auth` - The authentication component to install
+/// * `config` - Configuration for account creation; `init_storage_data` is not used since
+///   the component already carries its storage
+///
+/// # Returns
+/// The created `Account`. Since the account is new, `Account::seed()` returns the ID seed
+/// needed to re-add it to a fresh store. If an account with the same ID is already in the
+/// store (only possible with fixed `init_seed` and `key_seed`), the stored account is
+/// returned instead.
+///
+/// # Errors
+/// Returns an error if account creation, key generation, or client or keystore operations
+/// fail
+pub async fn create_account_from_component(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    component: AccountComponent,
+    auth: WalletAuth,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let init_seed = config.init_seed.unwrap_or_else(|| random_seed(client));
+    let builder = AccountBuilder::new(init_seed)
+        .account_type(config.account_type)
+        .with_component(component);
+
+    let account = add_account_with_auth(client, keystore, builder, auth, config.key_seed).await?;
+    println!("Account ID: {:?}", account.id());
+    Ok(account)
+}
+
 /// Creates an account with a custom component from a compiled package
 ///
 /// # Arguments
 /// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the authentication key, if `auth` has one
 /// * `package` - The compiled package containing the account component
+/// * `auth` - The authentication component to install
 /// * `config` - Configuration for account creation
 ///
 /// # Returns
-/// The created `Account`
+/// The created `Account`, as returned by [`create_account_from_component`].
 ///
 /// # Errors
-/// Returns an error if account creation or client operations fail
+/// Returns an error if the component cannot be built from the package, or if account
+/// creation or client operations fail
 pub async fn create_account_from_package(
     client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
     package: Arc<Package>,
+    auth: WalletAuth,
     config: AccountCreationConfig,
 ) -> Result<Account> {
     let account_component =
         AccountComponent::from_package(package.as_ref(), &config.init_storage_data)
             .context("Failed to create account component from package")?;
 
-    let mut init_seed = [0_u8; 32];
-    client.rng().fill_bytes(&mut init_seed);
-
-    let account = AccountBuilder::new(init_seed)
-        .account_type(config.account_type)
-        .with_component(account_component)
-        .with_auth_component(NoAuth)
-        .build()
-        .context("Failed to build account")?;
-
-    println!("Account ID: {:?}", account.id());
+    create_account_from_component(client, keystore, account_component, auth, config).await
+}
 
-    client
-        .add_account(&account, false)
-        .await
-        .context("Failed to add account to client")?;
+/// Authentication component installed on a new wallet or contract account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletAuth {
+    /// A single signature with a fresh key of the given scheme, stored in the keystore.
+    SingleSig(AuthSchemeId),
+    /// No authentication: anyone can execute transactions against the account. Only
+    /// suitable for open test accounts.
+    NoAuth,
+}
 
-    Ok(account)
+impl Default for WalletAuth {
+    fn default() -> Self {
+        WalletAuth::SingleSig(AuthSchemeId::Falcon512Poseidon2)
+    }
 }
 
 /// Creates a basic wallet account with authentication
 ///
+/// Uses a Falcon512 key, the default `WalletAuth`; see [`create_wallet_with_auth`] for
+/// other authentication components.
+///
+/// Calling this several times with the same keystore is safe: every account gets its own
+/// key, and the keystore indexes keys by account ID, so multi-account scenarios (e.g. an
+/// `alice` and a `bob` wallet) need no separate keystores.
+///
 /// # Arguments
 /// * `client` - The Miden client instance
 /// * `keystore` - The keystore for storing authentication keys
 /// * `config` - Configuration for account creation
 ///
 /// # Returns
-/// The created `Account` with basic wallet functionality
+/// The created `Account` with basic wallet functionality. Since the account is new,
+/// `Account::seed()` returns the ID seed needed to re-add it to a fresh store; the
+/// authentication key remains in `keystore`. If an account with the same ID is already in
+/// the store (only possible with fixed `init_seed` and `key_seed`), the stored account is
+/// returned and its existing key is kept.
 ///
 /// # Errors
 /// Returns an error if account creation, key generation, or keystore operations fail
@@ -195,32 +851,2093 @@ pub async fn create_basic_wallet_account(
     keystore: Arc<FilesystemKeyStore>,
     config: AccountCreationConfig,
 ) -> Result<Account> {
-    let mut init_seed = [0_u8; 32];
-    client.rng().fill_bytes(&mut init_seed);
-
-    let key_pair = AuthSecretKey::new_falcon512_poseidon2_with_rng(client.rng());
+    create_wallet_with_auth(client, keystore, WalletAuth::default(), config).await
+}
 
+/// Creates a basic wallet account with the given authentication component
+///
+/// With `WalletAuth::SingleSig` a key of the chosen scheme is generated and added to
+/// `keystore`; with a fixed `key_seed` the key is derived from it, which is only meant for
+/// tests. `WalletAuth::NoAuth` adds no key.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore for storing authentication keys
+/// * `auth` - The authentication component to install
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The created `Account`, or the stored account if one with the same ID is already in the
+/// store (only possible with fixed `init_seed` and `key_seed`)
+///
+/// # Errors
+/// Returns an error if account creation, key generation, or keystore operations fail
+pub async fn create_wallet_with_auth(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: Arc<FilesystemKeyStore>,
+    auth: WalletAuth,
+    config: AccountCreationConfig,
+) -> Result<Account> {
+    let init_seed = config.init_seed.unwrap_or_else(|| random_seed(client));
     let builder = AccountBuilder::new(init_seed)
         .account_type(config.account_type)
-        .with_auth_component(AuthSingleSig::new(
-            key_pair.public_key().to_commitment(),
-            AuthSchemeId::Falcon512Poseidon2,
-        ))
         .with_component(BasicWallet);
 
-    let account = builder
-        .build()
-        .context("Failed to build basic wallet account")?;
-
-    client
-        .add_account(&account, false)
-        .await
-        .context("Failed to add account to client")?;
+    add_account_with_auth(client, &keystore, builder, auth, config.key_seed).await
+}
 
-    keystore
-        .add_key(&key_pair, account.id())
+/// Installs the `auth` component on `builder`, then adds the built account to the client
+/// and its key, if any, to `keystore`
+///
+/// The key is derived from `key_seed` when given, otherwise from a random seed. If an
+/// account with the same ID is already in the store, it is returned and the keystore is
+/// left untouched.
+async fn add_account_with_auth(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    builder: AccountBuilder,
+    auth: WalletAuth,
+    key_seed: Option<[u8; 32]>,
+) -> Result<Account> {
+    let key_seed = key_seed.unwrap_or_else(|| random_seed(client));
+    let mut key_rng = StdRng::from_seed(key_seed);
+
+    let (builder, key_pair) = match auth {
+        WalletAuth::SingleSig(auth_scheme) => {
+            let key_pair = AuthSecretKey::with_scheme_and_rng(auth_scheme, &mut key_rng)
+                .context("Failed to generate authentication key")?;
+            let builder = builder.with_auth_component(AuthSingleSig::new(
+                key_pair.public_key().to_commitment(),
+                auth_scheme,
+            ));
+            (builder, Some(key_pair))
+        }
+        WalletAuth::NoAuth => (builder.with_auth_component(NoAuth), None),
+    };
+
+    let account = builder.build().context("Failed to build account")?;
+
+    if let Some(existing) = find_stored_account(client, account.id()).await? {
+        return Ok(existing);
+    }
+
+    client
+        .add_account(&account, false)
+        .await
+        .context("Failed to add account to client")?;
+
+    if let Some(key_pair) = key_pair {
+        keystore
+            .add_key(&key_pair, account.id())
+            .await
+            .context("Failed to add key to keystore")?;
+    }
+
+    Ok(account)
+}
+
+/// Creates a basic wallet account funded with `amount` tokens of `faucet_id`
+///
+/// Mints the tokens from the faucet as a private P2ID note, waits for the mint to be
+/// committed, consumes the note with the new wallet and waits for that as well. The
+/// faucet must be tracked by `client` and its key must be in `keystore`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore holding the faucet key, where the wallet key is stored too
+/// * `faucet_id` - The ID of the fungible faucet to mint from
+/// * `amount` - The number of tokens to mint into the wallet
+///
+/// # Returns
+/// The funded wallet `Account`, as stored after the consume transaction was committed
+///
+/// # Errors
+/// Returns an error if wallet creation, minting or consuming fails, if a transaction is
+/// not committed within the default timeout, or if the committed wallet balance does not
+/// equal `amount`
+pub async fn create_wallet_with_assets(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: Arc<FilesystemKeyStore>,
+    faucet_id: AccountId,
+    amount: u64,
+) -> Result<Account> {
+    let wallet =
+        create_basic_wallet_account(client, keystore, AccountCreationConfig::default()).await?;
+    let asset = FungibleAsset::new(faucet_id, amount).context("Invalid fungible asset")?;
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, wallet.id(), NoteType::Private, client.rng())
+        .context("Failed to build mint transaction request")?;
+    let mint_notes = mint_request.expected_output_own_notes();
+    submit_and_wait(client, faucet_id, mint_request)
+        .await
+        .context("Failed to mint tokens for the wallet")?;
+
+    let consume_request = TransactionRequestBuilder::new()
+        .build_consume_notes(mint_notes)
+        .context("Failed to build consume note transaction request")?;
+    submit_and_wait(client, wallet.id(), consume_request)
+        .await
+        .context("Failed to consume the mint note with the wallet")?;
+
+    let funded = client
+        .get_account(wallet.id())
+        .await
+        .context("Failed to load funded wallet from store")?
+        .ok_or_else(|| anyhow!("Wallet {} not found in store", wallet.id().to_hex()))?;
+    let balance = vault_fungible_balance(&funded, faucet_id);
+    if balance != amount {
+        bail!(
+            "Wallet {} holds {balance} tokens of faucet {} instead of {amount}",
+            wallet.id().to_hex(),
+            faucet_id.to_hex()
+        );
+    }
+
+    Ok(funded)
+}
+
+/// Returns the total amount of fungible assets issued by `faucet_id` in the account vault.
+fn vault_fungible_balance(account: &Account, faucet_id: AccountId) -> u64 {
+    account
+        .vault()
+        .assets()
+        .filter_map(|asset| match asset {
+            Asset::Fungible(fungible) if fungible.faucet_id() == faucet_id => {
+                Some(fungible.amount().as_u64())
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+/// Reads the balance of a fungible asset held by an account
+///
+/// The balance is read from the client store, so sync the client first to observe the
+/// latest on-chain state.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account holding the asset
+/// * `faucet_id` - The ID of the faucet issuing the asset
+///
+/// # Returns
+/// The amount of the faucet's asset in the account vault, or zero if it holds none
+///
+/// # Errors
+/// Returns an error if the account cannot be read from the store or is not tracked
+pub async fn fungible_balance(
+    client: &Client<FilesystemKeyStore>,
+    account_id: AccountId,
+    faucet_id: AccountId,
+) -> Result<u64> {
+    let account = client
+        .get_account(account_id)
+        .await
+        .context("Failed to get account from client")?
+        .ok_or_else(|| anyhow!("Account {} not found in store", account_id.to_hex()))?;
+    Ok(vault_fungible_balance(&account, faucet_id))
+}
+
+/// How the tag of a created note is chosen
+///
+/// The tag routes the note to the accounts that should discover it. Network notes must be
+/// tagged for the network account they target so the network transaction builder picks
+/// them up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagStrategy {
+    /// Tag the note for the account creating it.
+    CreatorAccount,
+    /// Tag the note for the account expected to consume it.
+    TargetAccount(AccountId),
+    /// Use the given tag as is.
+    Custom(NoteTag),
+}
+
+impl TagStrategy {
+    /// Returns the note tag for a note created by `sender`.
+    pub fn tag_for(&self, sender: AccountId) -> NoteTag {
+        match self {
+            TagStrategy::CreatorAccount => NoteTag::with_account_target(sender),
+            TagStrategy::TargetAccount(target) => NoteTag::with_account_target(*target),
+            TagStrategy::Custom(tag) => *tag,
+        }
+    }
+}
+
+/// Configuration for creating a note from a compiled note script package
+pub struct NoteCreationConfig {
+    /// Whether the note is public (fully shared with the network) or private.
+    pub note_type: NoteType,
+    /// Note inputs stored in the note's storage and readable by the note script.
+    pub note_storage: Vec<Felt>,
+    /// How the note tag is chosen. Defaults to tagging the note for its creator.
+    pub tag: TagStrategy,
+    /// Network account the note is targeted at. In protocol v0.15 this attachment
+    /// also carries the note's execution hint (e.g. `NoteExecutionHint::always()`).
+    pub network_target: Option<NetworkAccountTarget>,
+}
+
+impl Default for NoteCreationConfig {
+    fn default() -> Self {
+        Self {
+            note_type: NoteType::Public,
+            note_storage: Vec::new(),
+            tag: TagStrategy::CreatorAccount,
+            network_target: None,
+        }
+    }
+}
+
+/// Returns the note configuration for an increment note adding `amount` to the counter
+///
+/// The increment note script reads the amount from its note storage, so every increment
+/// note must carry it.
+///
+/// # Arguments
+/// * `amount` - The value the note adds to the counter when consumed
+///
+/// # Errors
+/// Returns an error if `amount` is not a valid field element
+pub fn increment_note_config(amount: u64) -> Result<NoteCreationConfig> {
+    let amount = Felt::new(amount).context("Increment amount is not a valid field element")?;
+    Ok(NoteCreationConfig {
+        note_storage: vec![amount],
+        ..Default::default()
+    })
+}
+
+/// Returns the note configuration for a network note executed by `target`
+///
+/// In protocol v0.15 the execution hint travels in the `NetworkAccountTarget` attachment,
+/// so this is how a note gets a hint such as `NoteExecutionHint::after_block(n)`. The note
+/// is public and tagged for `target` so the network transaction builder picks it up.
+///
+/// # Arguments
+/// * `target` - The public network account expected to consume the note
+/// * `hint` - When the note is expected to become executable
+///
+/// # Errors
+/// Returns an error if `target` is not a public account
+pub fn network_note_config(
+    target: AccountId,
+    hint: NoteExecutionHint,
+) -> Result<NoteCreationConfig> {
+    let network_target = NetworkAccountTarget::new(target, hint)
+        .context("Failed to create network account target")?;
+    Ok(NoteCreationConfig {
+        note_type: NoteType::Public,
+        tag: TagStrategy::TargetAccount(target),
+        network_target: Some(network_target),
+        ..Default::default()
+    })
+}
+
+/// Creates a note whose script is taken from a compiled package
+///
+/// # Arguments
+/// * `sender` - The ID of the account sending the note
+/// * `package` - The compiled package containing the note script
+/// * `rng` - Random number generator used to derive the note serial number
+/// * `config` - Configuration for note creation
+///
+/// # Returns
+/// The created `Note`
+///
+/// # Errors
+/// Returns an error if the note storage is invalid (e.g. exceeds the protocol limit of 1024
+/// items) or the note cannot be built from the package
+pub fn create_note_from_package(
+    sender: AccountId,
+    package: &Package,
+    rng: impl RngCore,
+    config: NoteCreationConfig,
+) -> Result<Note> {
+    let mut builder = NoteBuilder::new(sender, rng)
+        .package(package.clone())
+        .note_type(config.note_type)
+        .tag(config.tag.tag_for(sender).as_u32())
+        .note_storage(config.note_storage)
+        .context("Failed to set note storage")?;
+
+    if let Some(network_target) = config.network_target {
+        builder = builder.attachment(network_target);
+    }
+
+    builder.build().context("Failed to build note from package")
+}
+
+/// Reads the counter value written by an executed transaction from its account delta
+///
+/// Lets callers see the new counter value right after execution, without syncing or
+/// re-fetching the account from the node.
+///
+/// # Arguments
+/// * `tx` - A transaction executed against a counter account
+///
+/// # Returns
+/// The post-execution counter value, or `None` if the transaction did not write it
+pub fn counter_delta(tx: &ExecutedTransaction) -> Option<u64> {
+    let slot = counter_storage_slot().ok()?;
+    let key = StorageMapKey::new(COUNTER_STORAGE_KEY);
+    tx.account_delta()
+        .storage()
+        .maps()
+        .find(|(name, _)| **name == slot)
+        .and_then(|(_, map)| map.entries().get(&key))
+        // Map values are stored as scalar words in `[value, 0, 0, 0]` layout.
+        .map(|value| value[0].as_canonical_u64())
+}
+
+/// Client-side handle to a deployed counter account
+///
+/// Bundles the counter account ID with the increment note package so callers read the
+/// counter and build increment transactions without handling storage slots or package
+/// paths themselves.
+pub struct CounterClient {
+    counter_id: AccountId,
+    note_package: Arc<Package>,
+    storage_slot: StorageSlotName,
+}
+
+impl CounterClient {
+    /// Creates a handle for the counter account `counter_id`
+    ///
+    /// # Arguments
+    /// * `counter_id` - The ID of the counter account
+    /// * `note_package` - The compiled increment note package
+    ///
+    /// # Errors
+    /// Returns an error if the counter storage slot name is invalid
+    pub fn new(counter_id: AccountId, note_package: Arc<Package>) -> Result<Self> {
+        Ok(Self {
+            counter_id,
+            note_package,
+            storage_slot: counter_storage_slot()?,
+        })
+    }
+
+    /// Returns the ID of the counter account.
+    pub fn id(&self) -> AccountId {
+        self.counter_id
+    }
+
+    /// Reads the counter value from the client store without submitting a transaction
+    ///
+    /// The value reflects the state as of the client's last sync.
+    ///
+    /// # Arguments
+    /// * `client` - The Miden client instance tracking the counter account
+    ///
+    /// # Returns
+    /// The current counter value
+    ///
+    /// # Errors
+    /// Returns an error if the counter account is not in the store or its storage cannot
+    /// be read
+    pub async fn counter_value(&self, client: &Client<FilesystemKeyStore>) -> Result<u64> {
+        let storage = client
+            .get_account_storage(self.counter_id)
+            .await
+            .context(format!(
+                "Failed to read storage of counter account {}",
+                self.counter_id.to_hex()
+            ))?;
+        let value = storage
+            .get_map_item(&self.storage_slot, COUNTER_STORAGE_KEY)
+            .context("Failed to read counter value")?;
+        Ok(value[0].as_canonical_u64())
+    }
+
+    /// Creates an increment note adding `amount` to the counter when consumed
+    ///
+    /// The note is tagged for the counter account.
+    ///
+    /// # Arguments
+    /// * `sender` - The ID of the account sending the note
+    /// * `rng` - Random number generator used to derive the note serial number
+    /// * `amount` - The value the note adds to the counter
+    ///
+    /// # Errors
+    /// Returns an error if `amount` is not a valid field element or the note cannot be built
+    pub fn increment_note(
+        &self,
+        sender: AccountId,
+        rng: impl RngCore,
+        amount: u64,
+    ) -> Result<Note> {
+        let config = NoteCreationConfig {
+            tag: TagStrategy::TargetAccount(self.counter_id),
+            ..increment_note_config(amount)?
+        };
+        create_note_from_package(sender, &self.note_package, rng, config)
+    }
+
+    /// Builds the transaction request with which the counter consumes increment notes
+    ///
+    /// The request must be submitted for `CounterClient::id`.
+    ///
+    /// # Arguments
+    /// * `notes` - Published increment notes to consume
+    ///
+    /// # Errors
+    /// Returns an error if `notes` is empty or the request cannot be built
+    pub fn increment_request(&self, notes: Vec<Note>) -> Result<TransactionRequest> {
+        if notes.is_empty() {
+            bail!(
+                "No increment notes provided for counter {}",
+                self.counter_id.to_hex()
+            );
+        }
+
+        TransactionRequestBuilder::new()
+            .input_notes(notes.into_iter().map(|note| (note, None)))
+            .build()
+            .context("Failed to build increment transaction request")
+    }
+}
+
+/// Number of attempts the transaction helpers make to execute a transaction before giving up.
+pub const DEFAULT_SUBMIT_ATTEMPTS: u32 = 3;
+
+/// Returns whether `err` is a transient RPC failure that is worth retrying.
+fn is_transient_rpc_error(err: &ClientError) -> bool {
+    match err {
+        ClientError::RpcError(RpcError::ConnectionError(_)) => true,
+        ClientError::RpcError(RpcError::RequestError { error_kind, .. }) => matches!(
+            error_kind,
+            GrpcError::Unavailable
+                | GrpcError::DeadlineExceeded
+                | GrpcError::ResourceExhausted
+                | GrpcError::Aborted
+        ),
+        _ => false,
+    }
+}
+
+/// Executes a transaction locally without proving or submitting it
+///
+/// Nothing is written to the client store and nothing is sent to the node, so this can
+/// be used to check that a request succeeds and to inspect the resulting account delta
+/// before spending network state.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request to execute
+///
+/// # Returns
+/// The `ExecutedTransaction`, including the account delta and output notes
+///
+/// # Errors
+/// Returns an error if the account is not tracked by the client or execution fails
+pub async fn simulate_transaction(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+    request: TransactionRequest,
+) -> Result<ExecutedTransaction> {
+    let result = client
+        .execute_transaction(account_id, request)
+        .await
+        .context(format!(
+            "Failed to execute transaction for account {}",
+            account_id.to_hex()
+        ))?;
+    Ok(result.into())
+}
+
+/// Sleeps for `backoff` before the next retry attempt
+///
+/// # Errors
+/// Returns a `WaitCancelled` error if `cancellation_token` fires before or during the sleep
+async fn sleep_before_retry(
+    backoff: Duration,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<()> {
+    match cancellation_token {
+        Some(token) => tokio::select! {
+            _ = token.cancelled() => Err(WaitCancelled.into()),
+            _ = tokio::time::sleep(backoff) => Ok(()),
+        },
+        None => {
+            tokio::time::sleep(backoff).await;
+            Ok(())
+        }
+    }
+}
+
+/// Executes, proves and submits a transaction, retrying transient RPC failures
+///
+/// Connection failures and retryable gRPC statuses (unavailable, deadline exceeded,
+/// resource exhausted, aborted) while executing or proving the transaction are retried
+/// with exponential backoff starting at one second. Nothing has reached the node at that
+/// point, so a retry cannot submit the transaction twice.
+///
+/// The proven transaction is submitted exactly once. A submission that fails in transit
+/// (e.g. with a deadline exceeded) may still have been accepted by the node, and the
+/// request cannot be resubmitted without risking a second transaction, so the error is
+/// returned together with the transaction ID for the caller to check on the network.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request to submit
+/// * `max_attempts` - Maximum number of execution attempts, at least one is always made
+/// * `cancellation_token` - Token that interrupts the backoff between attempts
+///
+/// # Returns
+/// The `TransactionId` of the submitted transaction
+///
+/// # Errors
+/// Returns the last error if every execution attempt fails, the first non-transient
+/// error, a `WaitCancelled` error if the token is cancelled while backing off, or an error
+/// if the submission or the update of the local store fails
+pub async fn submit_with_retry(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+    request: TransactionRequest,
+    max_attempts: u32,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<TransactionId> {
+    // Mirrors `Client::submit_new_transaction`, which registers missing network note
+    // scripts with their own transactions before executing the request.
+    if !request.expected_ntx_scripts().is_empty() {
+        let prover = client.prover();
+        client
+            .ensure_ntx_scripts_registered(account_id, request.expected_ntx_scripts(), prover)
+            .await
+            .context("Failed to register network note scripts")?;
+    }
+
+    let mut backoff = Duration::from_secs(1);
+    let mut attempt = 1;
+    let (tx_result, proven_transaction) = loop {
+        let prepared = async {
+            let tx_result = client
+                .execute_transaction(account_id, request.clone())
+                .await?;
+            let proven_transaction = client.prove_transaction(&tx_result).await?;
+            Ok::<_, ClientError>((tx_result, proven_transaction))
+        }
+        .await;
+
+        match prepared {
+            Ok(prepared) => break prepared,
+            Err(err) if attempt < max_attempts && is_transient_rpc_error(&err) => {
+                println!(
+                    "Transaction execution attempt {attempt}/{max_attempts} failed: {err}; retrying in {backoff:?}"
+                );
+                sleep_before_retry(backoff, cancellation_token).await?;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(err) => {
+                return Err(err).context(format!(
+                    "Transaction execution failed after {attempt} attempt(s)"
+                ))
+            }
+        }
+    };
+
+    let tx_id = tx_result.executed_transaction().id();
+    let submission_height = client
+        .submit_proven_transaction(proven_transaction, &tx_result)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to submit transaction {}; it is not retried since the node may \
+                 have accepted it",
+                tx_id.to_hex()
+            )
+        })?;
+    client
+        .apply_transaction(&tx_result, submission_height)
+        .await
+        .with_context(|| {
+            format!(
+                "Transaction {} was submitted but could not be applied to the local store",
+                tx_id.to_hex()
+            )
+        })?;
+
+    Ok(tx_id)
+}
+
+/// Number of attempts `sync_with_retry` callers make by default before giving up.
+pub const DEFAULT_SYNC_ATTEMPTS: u32 = 5;
+
+/// Syncs the client state with the network, retrying transient RPC failures
+///
+/// Uses the same transient error classification and exponential backoff as
+/// `submit_with_retry`, so a node that is genuinely down still surfaces an error once
+/// the attempts are exhausted.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `max_attempts` - Maximum number of sync attempts, at least one is always made
+/// * `cancellation_token` - Token that interrupts the backoff between attempts
+///
+/// # Returns
+/// The `SyncSummary` of the successful sync
+///
+/// # Errors
+/// Returns the last error if every attempt fails, the first non-transient error, or a
+/// `WaitCancelled` error if the token is cancelled while backing off
+pub async fn sync_with_retry(
+    client: &mut Client<FilesystemKeyStore>,
+    max_attempts: u32,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<SyncSummary> {
+    let mut backoff = Duration::from_secs(1);
+    let mut attempt = 1;
+    loop {
+        match client.sync_state().await {
+            Ok(summary) => return Ok(summary),
+            Err(err) if attempt < max_attempts && is_transient_rpc_error(&err) => {
+                println!(
+                    "State sync attempt {attempt}/{max_attempts} failed: {err}; retrying in {backoff:?}"
+                );
+                sleep_before_retry(backoff, cancellation_token).await?;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(err) => {
+                return Err(err).context(format!("State sync failed after {attempt} attempt(s)"))
+            }
+        }
+    }
+}
+
+/// Publishes a note by submitting a transaction that creates it as an output note
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `sender` - The ID of the account creating the note
+/// * `note` - The note to publish
+///
+/// # Returns
+/// The `TransactionId` of the submitted transaction, to be passed to `wait_for_tx`
+///
+/// # Errors
+/// Returns an error if building the transaction request or submitting the transaction fails
+pub async fn publish_note(
+    client: &mut Client<FilesystemKeyStore>,
+    sender: AccountId,
+    note: Note,
+) -> Result<TransactionId> {
+    let note_publish_request = TransactionRequestBuilder::new()
+        .own_output_notes(vec![note])
+        .build()
+        .context("Failed to build note publish transaction request")?;
+
+    submit_with_retry(
+        client,
+        sender,
+        note_publish_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .context("Failed to create note publish transaction")
+}
+
+/// Creates and publishes a public P2ID note paying `assets` to `target`
+///
+/// The note uses the standard P2ID script and is tagged for `target`, so only that
+/// account can consume it and its client discovers it on sync. Consume it on the target's
+/// side with `consume_notes`.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `sender` - The ID of the account sending the assets
+/// * `target` - The ID of the account receiving the assets
+/// * `assets` - The assets moved from `sender` into the note
+///
+/// # Returns
+/// The created P2ID `Note` and the `TransactionId` of the submitted transaction
+///
+/// # Errors
+/// Returns an error if building the note or submitting the transaction fails
+pub async fn create_p2id_note(
+    client: &mut Client<FilesystemKeyStore>,
+    sender: AccountId,
+    target: AccountId,
+    assets: NoteAssets,
+) -> Result<(Note, TransactionId)> {
+    let p2id_note = P2idNote::create(
+        sender,
+        target,
+        assets.iter().copied().collect(),
+        NoteType::Public,
+        NoteAttachments::empty(),
+        client.rng(),
+    )
+    .context("Failed to create P2ID note")?;
+
+    let tx_id = publish_note(client, sender, p2id_note.clone()).await?;
+    Ok((p2id_note, tx_id))
+}
+
+/// Creates and publishes a SWAP note offering `offered` in exchange for `requested`
+///
+/// The note uses the standard SWAP script: whoever consumes it receives `offered` and
+/// must create a P2ID payback note carrying `requested` to `creator`. The payback note is
+/// registered as an expected note, so the creator's client picks it up on sync. Its
+/// details are also returned, since the consumer needs them to create the payback note
+/// (see [`consume_swap_note`]).
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `creator` - The ID of the account offering the asset
+/// * `offered` - The asset moved from `creator` into the SWAP note
+/// * `requested` - The asset `creator` wants in return
+/// * `note_type` - Visibility of both the SWAP note and the payback note
+///
+/// # Returns
+/// The created SWAP `Note`, the `NoteDetails` of the expected payback note and the
+/// `TransactionId` of the submitted transaction
+///
+/// # Errors
+/// Returns an error if `offered` equals `requested`, or if building the note, building the
+/// transaction request or submitting the transaction fails
+pub async fn create_swap_note(
+    client: &mut Client<FilesystemKeyStore>,
+    creator: AccountId,
+    offered: Asset,
+    requested: Asset,
+    note_type: NoteType,
+) -> Result<(Note, NoteDetails, TransactionId)> {
+    let swap_data = SwapTransactionData::new(creator, offered, requested);
+    let swap_request = TransactionRequestBuilder::new()
+        .build_swap(&swap_data, note_type, note_type, client.rng())
+        .context("Failed to build SWAP transaction request")?;
+
+    let swap_note = swap_request
+        .expected_output_own_notes()
+        .pop()
+        .context("SWAP transaction request creates no note")?;
+    let (payback_note_details, _) = swap_request
+        .expected_future_notes()
+        .next()
+        .cloned()
+        .context("SWAP transaction request expects no payback note")?;
+
+    let tx_id = submit_with_retry(client, creator, swap_request, DEFAULT_SUBMIT_ATTEMPTS, None)
+        .await
+        .context("Failed to create SWAP transaction")?;
+    Ok((swap_note, payback_note_details, tx_id))
+}
+
+/// Consumes a SWAP note, exchanging the requested asset for the offered one
+///
+/// Executing the SWAP script moves the offered asset into `consumer`'s vault and creates
+/// the P2ID payback note carrying the requested asset to the note's creator, so
+/// `consumer` must hold the requested asset. The SWAP note only commits to the payback
+/// recipient, so its details have to be shared by the creator.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `consumer` - The ID of the account taking the other side of the swap
+/// * `swap_note` - The SWAP note, as returned by [`create_swap_note`]
+/// * `payback_note` - The payback note details, as returned by [`create_swap_note`]
+///
+/// # Returns
+/// The `TransactionId` of the submitted consume transaction
+///
+/// # Errors
+/// Returns an error if `swap_note` does not use the standard SWAP script, or if consuming
+/// it fails (e.g. `consumer` does not hold the requested asset)
+pub async fn consume_swap_note(
+    client: &mut Client<FilesystemKeyStore>,
+    consumer: AccountId,
+    swap_note: Note,
+    payback_note: &NoteDetails,
+) -> Result<TransactionId> {
+    let note_id = swap_note.id();
+    if swap_note.script().root() != SwapNote::script_root() {
+        bail!("Note {note_id} is not a SWAP note");
+    }
+
+    let consume_request = TransactionRequestBuilder::new()
+        .input_notes(vec![(swap_note, None)])
+        .expected_output_recipients(vec![payback_note.recipient().clone()])
+        .build()
+        .context("Failed to build SWAP consume transaction request")?;
+
+    submit_with_retry(
+        client,
+        consumer,
+        consume_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .with_context(|| format!("Failed to consume SWAP note {note_id}"))
+}
+
+/// Consumes the given notes with the specified account in a single transaction
+///
+/// All notes are consumed atomically: either every note is consumed or the
+/// transaction fails as a whole.
+///
+/// The client treats every note whose inclusion proof it already tracks as an
+/// authenticated input note; all remaining notes are consumed as unauthenticated
+/// input notes.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `consumer` - The ID of the account consuming the notes
+/// * `notes` - The notes to consume, each paired with optional note arguments
+///
+/// # Returns
+/// The `TransactionId` of the submitted consume transaction
+///
+/// # Errors
+/// Returns an error if `notes` is empty, or if building the transaction request or
+/// submitting the transaction fails
+pub async fn consume_notes(
+    client: &mut Client<FilesystemKeyStore>,
+    consumer: AccountId,
+    notes: Vec<(Note, Option<NoteArgs>)>,
+) -> Result<TransactionId> {
+    if notes.is_empty() {
+        bail!(
+            "No notes provided to consume for account {}",
+            consumer.to_hex()
+        );
+    }
+
+    let consume_request = TransactionRequestBuilder::new()
+        .input_notes(notes)
+        .build()
+        .context("Failed to build consume note transaction request")?;
+
+    submit_with_retry(
+        client,
+        consumer,
+        consume_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .context("Failed to create consume note transaction")
+}
+
+/// Consumes a public note by ID, fetching it and its inclusion proof from the node
+///
+/// This lets a client that did not create the note consume it: the note is imported
+/// together with its inclusion proof, so it is consumed as an authenticated input
+/// note rather than an unauthenticated one.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `consumer` - The ID of the account consuming the note
+/// * `note_id` - The ID of the note to consume
+///
+/// # Returns
+/// The `TransactionId` of the submitted consume transaction
+///
+/// # Errors
+/// Returns an error if the note cannot be fetched from the node (e.g. it is private
+/// or not yet committed), if it was imported without an inclusion proof, or if
+/// submitting the consume transaction fails
+pub async fn consume_note_by_id(
+    client: &mut Client<FilesystemKeyStore>,
+    consumer: AccountId,
+    note_id: NoteId,
+) -> Result<TransactionId> {
+    client
+        .import_notes(&[NoteFile::NoteId(note_id)])
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to import note {} from the node; only committed public notes can be fetched by ID",
+                note_id.to_hex()
+            )
+        })?;
+
+    let record = client
+        .get_input_note(note_id)
+        .await
+        .context("Failed to load imported note from store")?
+        .ok_or_else(|| anyhow!("Note {} not found in store after import", note_id.to_hex()))?;
+    if record.inclusion_proof().is_none() {
+        bail!(
+            "Note {} was imported without an inclusion proof",
+            note_id.to_hex()
+        );
+    }
+    let note: Note = record
+        .try_into()
+        .context("Imported note record is missing note details")?;
+
+    consume_notes(client, consumer, vec![(note, None)]).await
+}
+
+/// Summary of a committed note the client considers consumable
+#[derive(Debug, Clone)]
+pub struct ConsumableNoteInfo {
+    /// ID of the note.
+    pub note_id: NoteId,
+    /// Whether the note is public or private.
+    pub note_type: NoteType,
+    /// Tag the note was routed with.
+    pub tag: NoteTag,
+    /// Accounts that may consume the note, each with its consumption status.
+    pub relevance: Vec<(AccountId, NoteConsumptionStatus)>,
+}
+
+/// Lists the committed notes the client considers consumable
+///
+/// Only the local store is queried; sync first to pick up newly committed notes.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - Restrict the result to notes consumable by this account, or `None`
+///   for notes consumable by any tracked account
+///
+/// # Returns
+/// One `ConsumableNoteInfo` per consumable note
+///
+/// # Errors
+/// Returns an error if the consumable notes cannot be read from the client
+pub async fn list_consumable_notes(
+    client: &Client<FilesystemKeyStore>,
+    account_id: Option<AccountId>,
+) -> Result<Vec<ConsumableNoteInfo>> {
+    let notes = client
+        .get_consumable_notes(account_id)
+        .await
+        .context("Failed to get consumable notes from client")?;
+
+    Ok(notes
+        .into_iter()
+        .filter_map(|(record, relevance)| {
+            // Committed notes always carry their ID and metadata.
+            let note_id = record.id()?;
+            let metadata = record.metadata()?;
+            Some(ConsumableNoteInfo {
+                note_id,
+                note_type: metadata.note_type(),
+                tag: metadata.tag(),
+                relevance,
+            })
+        })
+        .collect())
+}
+
+/// Prints the committed notes the client considers consumable as a table
+///
+/// Each row shows the note ID, type, tag and, per account that may consume the note,
+/// when it becomes consumable.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - Restrict the output to notes consumable by this account, or `None`
+///   for notes consumable by any tracked account
+///
+/// # Errors
+/// Returns an error if the consumable notes cannot be read from the client
+pub async fn print_consumable_notes(
+    client: &Client<FilesystemKeyStore>,
+    account_id: Option<AccountId>,
+) -> Result<()> {
+    let notes = list_consumable_notes(client, account_id).await?;
+    if notes.is_empty() {
+        println!("No consumable notes");
+        return Ok(());
+    }
+
+    println!("{:<66}  {:<8}  {:<10}  RELEVANCE", "NOTE ID", "TYPE", "TAG");
+    for note in notes {
+        let relevance = note
+            .relevance
+            .iter()
+            .map(|(account_id, status)| {
+                let status = match status {
+                    NoteConsumptionStatus::Consumable => "consumable".to_string(),
+                    NoteConsumptionStatus::ConsumableAfter(block_num) => {
+                        format!("consumable after block {block_num}")
+                    }
+                    NoteConsumptionStatus::ConsumableWithAuthorization => {
+                        "consumable with authorization".to_string()
+                    }
+                    NoteConsumptionStatus::UnconsumableConditions => "unconsumable".to_string(),
+                    NoteConsumptionStatus::NeverConsumable(err) => {
+                        format!("never consumable: {err}")
+                    }
+                };
+                format!("{}: {status}", account_id.to_hex())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{:<66}  {:<8}  {:<10}  {relevance}",
+            note.note_id.to_hex(),
+            note.note_type.to_string(),
+            note.tag.to_string()
+        );
+    }
+    Ok(())
+}
+
+/// Error returned by the wait helpers when their cancellation token fires.
+#[derive(Debug)]
+pub struct WaitCancelled;
+
+impl std::fmt::Display for WaitCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("wait was cancelled")
+    }
+}
+
+impl std::error::Error for WaitCancelled {}
+
+/// Error returned by `wait_for_tx` when the client does not track the transaction.
+///
+/// The client only records transactions it submitted itself, so an ID that stays unknown
+/// for several polls (e.g. one submitted by another process) will never be found.
+#[derive(Debug)]
+pub struct UnknownTransaction(pub TransactionId);
+
+impl std::fmt::Display for UnknownTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "transaction {} is not tracked by the client",
+            self.0.to_hex()
+        )
+    }
+}
+
+impl std::error::Error for UnknownTransaction {}
+
+/// Progress of a transaction observed by `wait_for_tx` on one poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStatus {
+    /// The client does not know the transaction yet.
+    NotFound,
+    /// The transaction was submitted but is not yet included in a block.
+    FoundPendingCommit,
+    /// The transaction is committed on chain.
+    Committed,
+}
+
+impl std::fmt::Display for WaitStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaitStatus::NotFound => f.write_str("not found"),
+            WaitStatus::FoundPendingCommit => f.write_str("pending commit"),
+            WaitStatus::Committed => f.write_str("committed"),
+        }
+    }
+}
+
+/// Callback receiving the `WaitStatus` of every poll
+pub type WaitStatusCallback = Arc<dyn Fn(WaitStatus) + Send + Sync>;
+
+/// Configuration for helpers that poll the network until a condition holds
+#[derive(Clone)]
+pub struct WaitConfig {
+    /// Maximum time to wait before giving up.
+    pub timeout: Duration,
+    /// Interval between consecutive state syncs.
+    pub poll_interval: Duration,
+    /// Token that aborts the wait with a `WaitCancelled` error when cancelled.
+    pub cancellation_token: Option<CancellationToken>,
+    /// Receives the status of every poll; when `None` the status is printed to stdout.
+    pub on_poll: Option<WaitStatusCallback>,
+    /// Consecutive polls a transaction may stay unknown to the client before `wait_for_tx`
+    /// fails with `UnknownTransaction`.
+    pub max_unknown_polls: u32,
+}
+
+impl Default for WaitConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            poll_interval: Duration::from_secs(2),
+            cancellation_token: None,
+            on_poll: None,
+            max_unknown_polls: 3,
+        }
+    }
+}
+
+impl WaitConfig {
+    /// Hands `status` to the `on_poll` callback, or prints it if none is set.
+    fn report(&self, subject: &str, status: WaitStatus) {
+        match &self.on_poll {
+            Some(on_poll) => on_poll(status),
+            None => println!("{subject}: {status}"),
+        }
+    }
+}
+
+/// Sleeps until the next poll of a wait loop started at `start`
+///
+/// # Errors
+/// Returns an error if `config.timeout` has elapsed, or a `WaitCancelled` error if the
+/// cancellation token fires before or during the sleep
+async fn sleep_until_next_poll(config: &WaitConfig, start: Instant) -> Result<()> {
+    if start.elapsed() >= config.timeout {
+        bail!("Timed out after {:?}", config.timeout);
+    }
+
+    match &config.cancellation_token {
+        Some(token) => tokio::select! {
+            _ = token.cancelled() => Err(WaitCancelled.into()),
+            _ = tokio::time::sleep(config.poll_interval) => Ok(()),
+        },
+        None => {
+            tokio::time::sleep(config.poll_interval).await;
+            Ok(())
+        }
+    }
+}
+
+/// Waits until a storage map entry of an account holds the expected value
+///
+/// Syncs the client state and re-reads the account until the scalar value stored
+/// under `key` equals `expected`, or until the wait times out or is cancelled.
+///
+/// The account is read from the local store, which applies the client's own transactions
+/// as soon as they are submitted, before they are committed. Use this helper only to
+/// observe changes made by other clients; to wait for a transaction submitted by this
+/// client, use `wait_for_tx` with its transaction ID.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account to observe
+/// * `slot` - The storage map slot to read from
+/// * `key` - The key of the map entry
+/// * `expected` - The value the map entry is expected to hold
+/// * `config` - Timeout, poll interval and cancellation settings
+///
+/// # Returns
+/// The `Account` state in which the map entry holds the expected value
+///
+/// # Errors
+/// Returns an error if syncing or reading the account fails, if the value does not
+/// match within the timeout, or `WaitCancelled` if the wait is cancelled
+pub async fn wait_for_account_map_value(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+    slot: &StorageSlotName,
+    key: Word,
+    expected: u64,
+    config: &WaitConfig,
+) -> Result<Account> {
+    let start = Instant::now();
+    loop {
+        client
+            .sync_state()
+            .await
+            .context("Failed to sync state while waiting for account")?;
+
+        let current = match client
+            .get_account(account_id)
+            .await
+            .context("Failed to get account from client")?
+        {
+            Some(account) => {
+                // Map values are stored as scalar words in `[value, 0, 0, 0]` layout.
+                let value = account
+                    .storage()
+                    .get_map_item(slot, key)
+                    .context("Failed to read storage map item")?[0]
+                    .as_canonical_u64();
+                if value == expected {
+                    return Ok(account);
+                }
+                Some(value)
+            }
+            None => None,
+        };
+
+        sleep_until_next_poll(config, start)
+            .await
+            .with_context(|| {
+                format!(
+                    "Waiting for account {} to hold {} in {}, last value: {:?}",
+                    account_id.to_hex(),
+                    expected,
+                    slot,
+                    current
+                )
+            })?;
+    }
+}
+
+/// Waits until a submitted transaction is committed on chain
+///
+/// The `WaitStatus` of every poll is passed to `config.on_poll`, or printed if no
+/// callback is set. A transaction the client does not track is reported as
+/// `UnknownTransaction` after `config.max_unknown_polls` polls instead of being awaited
+/// until the timeout.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `tx_id` - The ID of the transaction to wait for
+/// * `config` - Timeout, poll interval and cancellation settings
+///
+/// # Returns
+/// The committed `TransactionRecord`, including the block it was committed in
+///
+/// # Errors
+/// Returns an error if syncing fails, if the transaction is discarded or not committed
+/// within the timeout, `UnknownTransaction` if the client does not track it, or
+/// `WaitCancelled` if the wait is cancelled
+pub async fn wait_for_tx(
+    client: &mut Client<FilesystemKeyStore>,
+    tx_id: TransactionId,
+    config: &WaitConfig,
+) -> Result<TransactionRecord> {
+    let start = Instant::now();
+    let mut unknown_polls = 0;
+    loop {
+        client
+            .sync_state()
+            .await
+            .context("Failed to sync state while waiting for transaction")?;
+
+        let record = client
+            .get_transactions(TransactionFilter::Ids(vec![tx_id]))
+            .await
+            .context("Failed to get transactions from client")?
+            .into_iter()
+            .next();
+
+        let subject = format!("Transaction {}", tx_id.to_hex());
+        match record {
+            None => {
+                config.report(&subject, WaitStatus::NotFound);
+                unknown_polls += 1;
+                if unknown_polls >= config.max_unknown_polls {
+                    return Err(UnknownTransaction(tx_id).into());
+                }
+            }
+            Some(record) => match &record.status {
+                TransactionStatus::Committed { .. } => {
+                    config.report(&subject, WaitStatus::Committed);
+                    return Ok(record);
+                }
+                TransactionStatus::Discarded(cause) => {
+                    bail!("Transaction {} was discarded: {}", tx_id.to_hex(), cause)
+                }
+                TransactionStatus::Pending => {
+                    config.report(&subject, WaitStatus::FoundPendingCommit)
+                }
+            },
+        }
+
+        sleep_until_next_poll(config, start)
+            .await
+            .with_context(|| {
+                format!("Waiting for transaction {} to be committed", tx_id.to_hex())
+            })?;
+    }
+}
+
+/// Waits until a note tracked by the client has been consumed on chain
+///
+/// The note may be tracked either as an input note (e.g. received or imported) or as an
+/// output note the client created, such as a network note consumed by the network
+/// transaction builder. Use this instead of sleeping for a fixed time.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `note_id` - The ID of the note to wait for
+/// * `config` - Timeout, poll interval and cancellation settings
+///
+/// # Errors
+/// Returns an error if syncing or reading the note fails, if the note is not consumed
+/// within the timeout, or `WaitCancelled` if the wait is cancelled
+pub async fn wait_for_note_consumed(
+    client: &mut Client<FilesystemKeyStore>,
+    note_id: NoteId,
+    config: &WaitConfig,
+) -> Result<()> {
+    let start = Instant::now();
+    loop {
+        client
+            .sync_state()
+            .await
+            .context("Failed to sync state while waiting for note")?;
+
+        let input_consumed = client
+            .get_input_note(note_id)
+            .await
+            .context("Failed to get input note from client")?
+            .is_some_and(|record| record.is_consumed());
+        let output_consumed = client
+            .get_output_note(note_id)
+            .await
+            .context("Failed to get output note from client")?
+            .is_some_and(|record| record.is_consumed());
+        if input_consumed || output_consumed {
+            return Ok(());
+        }
+
+        sleep_until_next_poll(config, start)
+            .await
+            .with_context(|| format!("Waiting for note {} to be consumed", note_id.to_hex()))?;
+    }
+}
+
+/// Executes, proves and submits a transaction, then waits until it is committed
+///
+/// Submission goes through `submit_with_retry` and the wait uses the default
+/// `WaitConfig`, so both transient RPC failures and a discarded transaction surface as
+/// errors instead of being dropped.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the account executing the transaction
+/// * `request` - The transaction request to submit
+///
+/// # Returns
+/// The `TransactionId` of the committed transaction
+///
+/// # Errors
+/// Returns an error if submission fails, or if the transaction is discarded or not
+/// committed within the default timeout
+pub async fn submit_and_wait(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+    request: TransactionRequest,
+) -> Result<TransactionId> {
+    let tx_id =
+        submit_with_retry(client, account_id, request, DEFAULT_SUBMIT_ATTEMPTS, None).await?;
+    wait_for_tx(client, tx_id, &WaitConfig::default()).await?;
+    Ok(tx_id)
+}
+
+/// Syncs the client until it has observed the chain at or past `target`
+///
+/// Returns immediately after the first sync if the client is already past `target`,
+/// so it is safe to call repeatedly.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `target` - The block number the client must reach
+/// * `config` - Timeout, poll interval and cancellation settings
+///
+/// # Returns
+/// The `SyncSummary` of the sync that reached `target`
+///
+/// # Errors
+/// Returns an error if syncing fails, if `target` is not reached within the timeout,
+/// or `WaitCancelled` if the wait is cancelled
+pub async fn sync_until_block(
+    client: &mut Client<FilesystemKeyStore>,
+    target: BlockNumber,
+    config: &WaitConfig,
+) -> Result<SyncSummary> {
+    let start = Instant::now();
+    loop {
+        let summary = client
+            .sync_state()
+            .await
+            .context("Failed to sync state while waiting for block")?;
+        if summary.block_num >= target {
+            return Ok(summary);
+        }
+
+        let synced = summary.block_num;
+        sleep_until_next_poll(config, start)
+            .await
+            .with_context(|| format!("Waiting for block {target}, last synced block: {synced}"))?;
+    }
+}
+
+/// Syncs the client and returns the latest block number of the chain
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+///
+/// # Returns
+/// The block number the client is synced to
+///
+/// # Errors
+/// Returns an error if syncing fails
+pub async fn current_block(client: &mut Client<FilesystemKeyStore>) -> Result<BlockNumber> {
+    let summary = client
+        .sync_state()
+        .await
+        .context("Failed to sync state to read the current block")?;
+    Ok(summary.block_num)
+}
+
+/// Waits until the chain reaches `target`, using the default `WaitConfig`
+///
+/// Prefer this over fixed sleeps when waiting for the network to make progress, e.g. for
+/// network notes to be consumed. Use `sync_until_block` to customize the timeout or to
+/// make the wait cancellable.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `target` - The block number the chain must reach
+///
+/// # Returns
+/// The block number the client synced to, at or past `target`
+///
+/// # Errors
+/// Returns an error if syncing fails or `target` is not reached within the default timeout
+pub async fn wait_for_block(
+    client: &mut Client<FilesystemKeyStore>,
+    target: BlockNumber,
+) -> Result<BlockNumber> {
+    let summary = sync_until_block(client, target, &WaitConfig::default()).await?;
+    Ok(summary.block_num)
+}
+
+/// Exports an account tracked by the client, together with its authentication keys, to a file
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore holding the account's authentication keys
+/// * `account_id` - The ID of the account to export
+/// * `path` - Destination file path
+///
+/// # Errors
+/// Returns an error if the account is not tracked by the client, if one of its keys cannot
+/// be read from the keystore, or if the file cannot be written
+pub async fn export_account(
+    client: &Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    account_id: AccountId,
+    path: &Path,
+) -> Result<()> {
+    let account = client
+        .try_get_account(account_id)
+        .await
+        .context(format!("Failed to get account {}", account_id.to_hex()))?;
+
+    // Accounts without an auth key (e.g. `NoAuth` components) have no entry in the
+    // keystore index, which the lookup reports as a storage error. Reading the indexed
+    // keys themselves must succeed.
+    let key_commitments = match keystore.get_account_key_commitments(&account_id).await {
+        Ok(commitments) => commitments,
+        Err(KeyStoreError::StorageError(_)) => BTreeSet::new(),
+        Err(err) => {
+            return Err(err).context(format!(
+                "Failed to look up auth keys of account {}",
+                account_id.to_hex()
+            ))
+        }
+    };
+    let mut auth_secret_keys = Vec::with_capacity(key_commitments.len());
+    for commitment in key_commitments {
+        let key = keystore
+            .get_key(commitment)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to read auth key of account {} from keystore",
+                    account_id.to_hex()
+                )
+            })?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Auth key of account {} is indexed but missing from keystore",
+                    account_id.to_hex()
+                )
+            })?;
+        auth_secret_keys.push(key);
+    }
+
+    AccountFile::new(account, auth_secret_keys)
+        .write(path)
+        .context(format!("Failed to write account file {}", path.display()))
+}
+
+/// Imports an account previously written by `export_account` into the client and keystore
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the account's authentication keys
+/// * `path` - Path of the account file
+///
+/// # Returns
+/// The imported `Account`
+///
+/// # Errors
+/// Returns an error if the file does not exist, is not a valid account file (e.g. it was
+/// written by an incompatible protocol version), or the account or its keys cannot be added
+pub async fn import_account_from_file(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    path: &Path,
+) -> Result<Account> {
+    let account_file = match AccountFile::read(path) {
+        Ok(account_file) => account_file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("Account file not found at {}", path.display())
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::InvalidData => bail!(
+            "{} is not a valid account file or was written by an incompatible protocol version",
+            path.display()
+        ),
+        Err(err) => {
+            return Err(err).context(format!("Failed to read account file {}", path.display()))
+        }
+    };
+    let AccountFile {
+        account,
+        auth_secret_keys,
+    } = account_file;
+
+    client
+        .add_account(&account, false)
         .await
-        .context("Failed to add key to keystore")?;
+        .context("Failed to add account to client")?;
+
+    for key in &auth_secret_keys {
+        keystore
+            .add_key(key, account.id())
+            .await
+            .context("Failed to add key to keystore")?;
+    }
 
     Ok(account)
 }
+
+/// Imports a public account from the network and returns its current state
+///
+/// If the account is already tracked by the client, its stored state is overwritten with
+/// the state fetched from the network.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the public account to fetch
+///
+/// # Returns
+/// The imported `Account`
+///
+/// # Errors
+/// Returns an error if the account is private or not found on chain, or if it is missing
+/// from the store after the import
+pub async fn fetch_account(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+) -> Result<Account> {
+    client
+        .import_account_by_id(account_id)
+        .await
+        .context(format!(
+            "Failed to import account {} from the network",
+            account_id.to_hex()
+        ))?;
+
+    client
+        .get_account(account_id)
+        .await
+        .context("Failed to get imported account from client store")?
+        .ok_or_else(|| {
+            anyhow!(
+                "Account {} not found in the store after import",
+                account_id.to_hex()
+            )
+        })
+}
+
+/// Imports a public account from the network unless the client already tracks it
+///
+/// Unlike `fetch_account`, an account that is already in the store is left untouched, so
+/// re-running a script against the same store is safe and needs no network round trip.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `account_id` - The ID of the public account to import
+///
+/// # Errors
+/// Returns an error if the store lookup fails, or if the account is absent from the store
+/// and cannot be imported (e.g. it is private or not found on chain)
+pub async fn ensure_account_imported(
+    client: &mut Client<FilesystemKeyStore>,
+    account_id: AccountId,
+) -> Result<()> {
+    let stored = client
+        .get_account(account_id)
+        .await
+        .context("Failed to look up account in client store")?;
+    if stored.is_some() {
+        return Ok(());
+    }
+
+    match client.import_account_by_id(account_id).await {
+        // Another import may have raced us between the lookup and the import.
+        Ok(()) | Err(ClientError::AccountAlreadyTracked(_)) => Ok(()),
+        Err(err) => Err(err).context(format!(
+            "Failed to import account {} from the network",
+            account_id.to_hex()
+        )),
+    }
+}
+
+/// Record of a deployed contract account, stored in a JSON deployment manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentRecord {
+    /// Name the contract is looked up by, e.g. `counter-account`.
+    pub name: String,
+    /// Hex-encoded ID of the deployed account.
+    pub contract_id: String,
+    /// Bech32 address of the deployed account on its network.
+    pub contract_address: String,
+    /// Hex-encoded commitment of the account state the contract was created with.
+    pub initial_commitment: String,
+    /// Name of the network the account was deployed to.
+    pub network: String,
+    /// Block in which the deploying transaction was committed.
+    pub block_num: u32,
+    /// Hex-encoded ID of the deploying transaction.
+    pub tx_id: String,
+}
+
+impl DeploymentRecord {
+    /// Records the deployment of `account`, as created, by the transaction `tx_id`
+    /// committed in `block_number` on `network`.
+    pub fn new(
+        name: String,
+        account: &Account,
+        network: &Network,
+        tx_id: TransactionId,
+        block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            name,
+            contract_id: account.id().to_hex(),
+            contract_address: account.id().to_bech32(network.network_id()),
+            initial_commitment: account.to_commitment().to_hex(),
+            network: network.to_string(),
+            block_num: block_number.as_u32(),
+            tx_id: tx_id.to_hex(),
+        }
+    }
+}
+
+/// Writes a deployment manifest to a JSON file, replacing any previous contents
+///
+/// # Arguments
+/// * `path` - Destination file path, e.g. `deployment.json`
+/// * `entries` - The deployments to record
+///
+/// # Errors
+/// Returns an error if serialization or writing the file fails
+pub fn write_deployment_manifest(path: &Path, entries: &[DeploymentRecord]) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(entries).context("Failed to serialize deployment manifest")?;
+    std::fs::write(path, json).context(format!(
+        "Failed to write deployment manifest to {}",
+        path.display()
+    ))
+}
+
+/// Reads a deployment manifest previously written by `write_deployment_manifest`
+///
+/// # Arguments
+/// * `path` - Path of the deployment manifest
+///
+/// # Returns
+/// The recorded deployments, in the order they were written
+///
+/// # Errors
+/// Returns an error if the file cannot be read or is not a valid deployment manifest
+pub fn read_deployment_manifest(path: &Path) -> Result<Vec<DeploymentRecord>> {
+    let json = std::fs::read_to_string(path).context(format!(
+        "Failed to read deployment manifest from {}",
+        path.display()
+    ))?;
+    serde_json::from_str(&json).context(format!(
+        "Failed to parse deployment manifest in {}",
+        path.display()
+    ))
+}
+
+/// Adds `record` to the deployment manifest at `path`
+///
+/// A previous record with the same name is replaced; other records are kept. The manifest
+/// is created if it does not exist yet.
+///
+/// # Errors
+/// Returns an error if an existing manifest cannot be read, or if writing it fails
+pub fn record_deployment(path: &Path, record: DeploymentRecord) -> Result<()> {
+    let mut entries = if path.exists() {
+        read_deployment_manifest(path)?
+    } else {
+        Vec::new()
+    };
+    entries.retain(|entry| entry.name != record.name);
+    entries.push(record);
+    write_deployment_manifest(path, &entries)
+}
+
+/// Returns the deployment recorded under `name`, if any
+pub fn find_deployment<'a>(
+    entries: &'a [DeploymentRecord],
+    name: &str,
+) -> Option<&'a DeploymentRecord> {
+    entries.iter().find(|entry| entry.name == name)
+}
+
+/// Contract account to deploy with `deploy_contract` or `deploy_many`
+pub struct ContractSpec {
+    /// Name the contract is recorded under in the deployment manifest.
+    pub name: String,
+    /// The compiled package containing the account component.
+    pub package: Arc<Package>,
+    /// The authentication component installed on the contract account.
+    pub auth: WalletAuth,
+    /// Configuration for account creation.
+    pub config: AccountCreationConfig,
+}
+
+/// Creates a contract account and deploys it on chain with an empty transaction
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the authentication key of the contract, if any
+/// * `network` - The network the client is connected to, used for the bech32 address
+/// * `spec` - The contract to deploy
+///
+/// # Returns
+/// The `DeploymentRecord` of the committed deployment
+///
+/// # Errors
+/// Returns an error if the component cannot be built from the package, or if
+/// [`deploy_component`] fails
+pub async fn deploy_contract(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    network: &Network,
+    spec: ContractSpec,
+) -> Result<DeploymentRecord> {
+    let component =
+        AccountComponent::from_package(spec.package.as_ref(), &spec.config.init_storage_data)
+            .with_context(|| format!("Failed to create component for contract {}", spec.name))?;
+    deploy_component(
+        client,
+        keystore,
+        network,
+        spec.name,
+        component,
+        spec.auth,
+        spec.config,
+    )
+    .await
+}
+
+/// Creates an account from any account component and deploys it on chain with an empty
+/// transaction
+///
+/// Both `WalletAuth` components increment the nonce of a new account even when the
+/// transaction changes nothing else, so an empty transaction is enough to publish the
+/// account. With `WalletAuth::SingleSig` the transaction is signed with the new key.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the authentication key of the contract, if any
+/// * `network` - The network the client is connected to, used for the bech32 address
+/// * `name` - Name the contract is recorded under in the deployment manifest
+/// * `component` - The account component to deploy
+/// * `auth` - The authentication component installed on the contract account
+/// * `config` - Configuration for account creation
+///
+/// # Returns
+/// The `DeploymentRecord` of the committed deployment
+///
+/// # Errors
+/// Returns an error if account creation fails, or if the deploying transaction fails or
+/// is not committed within the default timeout
+pub async fn deploy_component(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    network: &Network,
+    name: String,
+    component: AccountComponent,
+    auth: WalletAuth,
+    config: AccountCreationConfig,
+) -> Result<DeploymentRecord> {
+    let account = create_account_from_component(client, keystore, component, auth, config)
+        .await
+        .with_context(|| format!("Failed to create account for contract {name}"))?;
+
+    let deploy_request = TransactionRequestBuilder::new()
+        .build()
+        .context("Failed to build deployment transaction request")?;
+    let tx_id = submit_with_retry(
+        client,
+        account.id(),
+        deploy_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .with_context(|| format!("Failed to deploy contract {name}"))?;
+    let record = wait_for_tx(client, tx_id, &WaitConfig::default())
+        .await
+        .with_context(|| format!("Deployment of contract {name} was not committed"))?;
+    let TransactionStatus::Committed { block_number, .. } = record.status else {
+        unreachable!("wait_for_tx only returns committed transactions");
+    };
+
+    Ok(DeploymentRecord::new(
+        name,
+        &account,
+        network,
+        tx_id,
+        block_number,
+    ))
+}
+
+/// Deploys several contract accounts and records each in the deployment manifest
+///
+/// Contracts are deployed one after another. Each record is written to the manifest as
+/// soon as its deployment is committed, so contracts deployed before a failure are kept.
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `keystore` - The keystore receiving the authentication keys of the contracts
+/// * `network` - The network the client is connected to
+/// * `specs` - The contracts to deploy
+/// * `manifest_path` - Path of the deployment manifest to update
+///
+/// # Returns
+/// The `DeploymentRecord` of every deployed contract, in the order of `specs`
+///
+/// # Errors
+/// Returns an error if deploying a contract or updating the manifest fails
+pub async fn deploy_many(
+    client: &mut Client<FilesystemKeyStore>,
+    keystore: &FilesystemKeyStore,
+    network: &Network,
+    specs: Vec<ContractSpec>,
+    manifest_path: &Path,
+) -> Result<Vec<DeploymentRecord>> {
+    let mut records = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let record = deploy_contract(client, keystore, network, spec).await?;
+        record_deployment(manifest_path, record.clone())?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Interface under which the compiler exports the entrypoint of a `tx-script` package
+const TX_SCRIPT_INTERFACE: &str = "miden:base/transaction-script@1.0.0";
+
+/// Loads a transaction script from a compiled `tx-script` package
+///
+/// The compiler emits transaction scripts as libraries, so the script entrypoint is resolved
+/// from the `run` procedure exported under the transaction script interface rather than from
+/// a program `main`.
+///
+/// # Arguments
+/// * `package` - The compiled transaction script package
+///
+/// # Returns
+/// The transaction script with `run` as its entrypoint
+///
+/// # Errors
+/// Returns an error if the package does not export the transaction script `run` procedure
+pub fn tx_script_from_package(package: &Package) -> Result<TransactionScript> {
+    let entrypoint = package
+        .mast
+        .exports()
+        .filter_map(|export| export.as_procedure())
+        .find(|procedure| {
+            procedure.path.last() == Some("run")
+                && procedure.path.parent().and_then(|parent| parent.last())
+                    == Some(TX_SCRIPT_INTERFACE)
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Package '{}' does not export a `{TX_SCRIPT_INTERFACE}` run procedure",
+                package.name
+            )
+        })?;
+
+    Ok(TransactionScript::from_parts(
+        package.mast.mast_forest().clone(),
+        entrypoint.node,
+    ))
+}
+
+/// Builds the transaction request resetting a counter to `value`
+///
+/// The request runs the reset transaction script, which calls the counter component's
+/// `set_count` procedure with the first element of the script argument. It must be
+/// executed against the counter account itself. `set_count` rejects transactions that
+/// consume notes, so no notes may be added to the request.
+///
+/// Counters created with `create_account_from_package` use `NoAuth`, so anyone can submit
+/// this request against them and reset the counter.
+///
+/// # Arguments
+/// * `script_package` - The compiled `reset-counter-script` package
+/// * `value` - The value to reset the counter to
+///
+/// # Errors
+/// Returns an error if the script cannot be loaded from the package, if `value` is not a
+/// valid field element, or if building the request fails
+pub fn reset_counter_request(script_package: &Package, value: u64) -> Result<TransactionRequest> {
+    let reset_script = tx_script_from_package(script_package)
+        .context("Failed to load reset transaction script from package")?;
+
+    let value = Felt::new(value).context("Counter value is not a valid field element")?;
+
+    TransactionRequestBuilder::new()
+        .custom_script(reset_script)
+        .script_arg(Word::new([value, Felt::ZERO, Felt::ZERO, Felt::ZERO]))
+        .build()
+        .context("Failed to build reset counter transaction request")
+}
+
+/// Resets the counter of a counter account to `value` with the reset transaction script
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `counter_id` - The ID of the counter account
+/// * `script_package` - The compiled `reset-counter-script` package
+/// * `value` - The value to reset the counter to
+///
+/// # Returns
+/// The `TransactionId` of the submitted reset transaction
+///
+/// # Errors
+/// Returns an error if building the request with `reset_counter_request` or submitting
+/// the transaction fails
+pub async fn reset_counter(
+    client: &mut Client<FilesystemKeyStore>,
+    counter_id: AccountId,
+    script_package: &Package,
+    value: u64,
+) -> Result<TransactionId> {
+    let reset_request = reset_counter_request(script_package, value)?;
+
+    submit_with_retry(
+        client,
+        counter_id,
+        reset_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .context("Failed to create reset counter transaction")
+}
+
+/// Builds the transaction request incrementing a counter `times` times in one transaction
+///
+/// The request runs the batch increment transaction script, which calls the counter
+/// component's `increment_count` procedure in a loop, so the counter advances by `times`
+/// in a single block. It must be executed against the counter account itself.
+///
+/// # Arguments
+/// * `script_package` - The compiled `increment-counter-script` package
+/// * `times` - How many times to increment the counter
+///
+/// # Errors
+/// Returns an error if the script cannot be loaded from the package or building the
+/// request fails
+pub fn batch_increment_request(script_package: &Package, times: u32) -> Result<TransactionRequest> {
+    let increment_script = tx_script_from_package(script_package)
+        .context("Failed to load batch increment transaction script from package")?;
+
+    TransactionRequestBuilder::new()
+        .custom_script(increment_script)
+        .script_arg(Word::new([
+            Felt::from(times),
+            Felt::ZERO,
+            Felt::ZERO,
+            Felt::ZERO,
+        ]))
+        .build()
+        .context("Failed to build batch increment transaction request")
+}
+
+/// Increments the counter of a counter account `times` times in a single transaction
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `counter_id` - The ID of the counter account
+/// * `script_package` - The compiled `increment-counter-script` package
+/// * `times` - How many times to increment the counter
+///
+/// # Returns
+/// The `TransactionId` of the submitted increment transaction
+///
+/// # Errors
+/// Returns an error if building the request with `batch_increment_request` or submitting
+/// the transaction fails
+pub async fn batch_increment(
+    client: &mut Client<FilesystemKeyStore>,
+    counter_id: AccountId,
+    script_package: &Package,
+    times: u32,
+) -> Result<TransactionId> {
+    let increment_request = batch_increment_request(script_package, times)?;
+
+    submit_with_retry(
+        client,
+        counter_id,
+        increment_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .context("Failed to create batch increment transaction")
+}
+
+/// Builds the transaction request decrementing a counter by one
+///
+/// The request runs the decrement transaction script. The counter component's
+/// `decrement_count` procedure traps when the counter is zero, so the transaction fails
+/// instead of wrapping the value around the field modulus.
+///
+/// # Arguments
+/// * `script_package` - The compiled `decrement-counter-script` package
+///
+/// # Errors
+/// Returns an error if the script cannot be loaded from the package or building the
+/// request fails
+pub fn decrement_counter_request(script_package: &Package) -> Result<TransactionRequest> {
+    let decrement_script = tx_script_from_package(script_package)
+        .context("Failed to load decrement transaction script from package")?;
+
+    TransactionRequestBuilder::new()
+        .custom_script(decrement_script)
+        .build()
+        .context("Failed to build decrement counter transaction request")
+}
+
+/// Decrements the counter of a counter account by one with the decrement transaction script
+///
+/// # Arguments
+/// * `client` - The Miden client instance
+/// * `counter_id` - The ID of the counter account
+/// * `script_package` - The compiled `decrement-counter-script` package
+///
+/// # Returns
+/// The `TransactionId` of the submitted decrement transaction
+///
+/// # Errors
+/// Returns an error if building the request, if the counter is zero, or if submitting the
+/// transaction fails
+pub async fn decrement_counter(
+    client: &mut Client<FilesystemKeyStore>,
+    counter_id: AccountId,
+    script_package: &Package,
+) -> Result<TransactionId> {
+    let decrement_request = decrement_counter_request(script_package)?;
+
+    submit_with_retry(
+        client,
+        counter_id,
+        decrement_request,
+        DEFAULT_SUBMIT_ATTEMPTS,
+        None,
+    )
+    .await
+    .context("Failed to create decrement counter transaction")
+}
@@ -1 +1,3 @@
 pub mod helpers;
+#[cfg(feature = "testing")]
+pub mod testing;
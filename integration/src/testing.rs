@@ -0,0 +1,390 @@
+//! Fixtures and assertion helpers for integration tests
+
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use miden_client::{
+    account::{
+        component::{
+            BasicWallet, BurnPolicyConfig, FungibleFaucet, MintPolicyConfig, PolicyRegistration,
+            TokenPolicyManager,
+        },
+        Account, AccountBuilder, AccountComponent, AccountId, AccountType,
+    },
+    asset::{Asset, AssetAmount, TokenSymbol},
+    auth::{AuthSchemeId, AuthSecretKey, AuthSingleSig},
+    builder::ClientBuilder,
+    keystore::{FilesystemKeyStore, Keystore},
+    rpc::Endpoint,
+    sync::SyncSummary,
+    testing::mock::MockRpcApi,
+    transaction::{TransactionRequest, TransactionScriptTemplate},
+    Client,
+};
+use miden_client_sqlite_store::ClientBuilderSqliteExt;
+use miden_standards::account::faucets::TokenName;
+use miden_testing::{AccountState, Auth, MockChain, MockChainBuilder, TransactionContextBuilder};
+use rand::{rngs::StdRng, SeedableRng};
+use tokio::task::JoinHandle;
+
+use crate::helpers::{
+    counter_init_storage_data, counter_package, counter_storage_slot, setup_client_with_retry,
+    ClientConfig, ClientSetup, Network, StoreKind, COUNTER_STORAGE_KEY, DEFAULT_SYNC_ATTEMPTS,
+};
+
+/// Seed of the counter account created by [`add_counter_account`].
+const COUNTER_ACCOUNT_SEED: [u8; 32] = [3_u8; 32];
+
+/// Environment variable overriding the node endpoint used by `setup_test_client`.
+pub const TEST_ENDPOINT_ENV: &str = "MIDEN_TEST_ENDPOINT";
+
+/// A client connected to a `MockChain` through the mock RPC API
+///
+/// Transactions submitted by the client are only committed once [`MockClient::prove_block`]
+/// is called, so tests control exactly when blocks are produced. The client uses an
+/// in-memory store and a temporary keystore that is removed on drop.
+pub struct MockClient {
+    /// The client, usable with all helpers taking a `Client<FilesystemKeyStore>`.
+    pub client: Client<FilesystemKeyStore>,
+    /// The keystore of the client.
+    pub keystore: Arc<FilesystemKeyStore>,
+    rpc: MockRpcApi,
+    keystore_path: PathBuf,
+}
+
+impl MockClient {
+    /// Creates a client whose node is `mock_chain`
+    ///
+    /// # Errors
+    /// Returns an error if the keystore or the client cannot be built
+    pub async fn new(mock_chain: MockChain) -> Result<Self> {
+        Self::with_rpc(MockRpcApi::new(mock_chain)).await
+    }
+
+    /// Creates another client connected to the same mock chain
+    ///
+    /// Both clients see the transactions and notes of the other once a block is proven,
+    /// but each has its own store and keystore, like two users of the same network.
+    ///
+    /// # Errors
+    /// Returns an error if the keystore or the client cannot be built
+    pub async fn connect_peer(&self) -> Result<Self> {
+        Self::with_rpc(self.rpc.clone()).await
+    }
+
+    /// Builds a client on top of `rpc` with a fresh in-memory store and keystore.
+    async fn with_rpc(rpc: MockRpcApi) -> Result<Self> {
+        static NEXT_KEYSTORE_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let keystore_id = NEXT_KEYSTORE_ID.fetch_add(1, Ordering::Relaxed);
+        let keystore_path = std::env::temp_dir().join(format!(
+            "miden-mock-client-keystore-{}-{keystore_id}",
+            std::process::id()
+        ));
+        let keystore = Arc::new(
+            FilesystemKeyStore::new(keystore_path.clone())
+                .context("Failed to initialize keystore")?,
+        );
+
+        let client = ClientBuilder::new()
+            .rpc(Arc::new(rpc.clone()))
+            .sqlite_store(StoreKind::InMemory.database_path())
+            .authenticator(keystore.clone())
+            .in_debug_mode(true.into())
+            .build()
+            .await
+            .context("Failed to build mock client")?;
+
+        Ok(Self {
+            client,
+            keystore,
+            rpc,
+            keystore_path,
+        })
+    }
+
+    /// Tracks an account that already exists on the mock chain, adding its key if it has one
+    ///
+    /// # Errors
+    /// Returns an error if the account or its key cannot be added
+    pub async fn track_account(
+        &mut self,
+        account: &Account,
+        key: Option<&AuthSecretKey>,
+    ) -> Result<()> {
+        self.client
+            .add_account(account, false)
+            .await
+            .context("Failed to add account to mock client")?;
+        if let Some(key) = key {
+            self.keystore
+                .add_key(key, account.id())
+                .await
+                .context("Failed to add key to keystore")?;
+        }
+        Ok(())
+    }
+
+    /// Commits all submitted transactions in a new block and syncs the client to it
+    ///
+    /// # Errors
+    /// Returns an error if the sync fails
+    pub async fn prove_block(&mut self) -> Result<SyncSummary> {
+        self.rpc.prove_block();
+        self.client
+            .sync_state()
+            .await
+            .context("Failed to sync mock client")
+    }
+
+    /// Proves a block every `interval` in the background until the returned guard is dropped
+    ///
+    /// Helpers that wait for their transactions to be committed (e.g. `submit_and_wait`)
+    /// would otherwise time out, since the mock chain only produces blocks on demand.
+    pub fn produce_blocks(&self, interval: Duration) -> BlockProducer {
+        let rpc = self.rpc.clone();
+        BlockProducer(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                rpc.prove_block();
+            }
+        }))
+    }
+}
+
+/// Background task proving mock chain blocks, stopped when dropped
+///
+/// Returned by [`MockClient::produce_blocks`].
+pub struct BlockProducer(JoinHandle<()>);
+
+impl Drop for BlockProducer {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl Drop for MockClient {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.keystore_path);
+    }
+}
+
+/// Builds a public basic wallet holding `assets`, as if it had been deployed already
+///
+/// Add the account to the `MockChain` builder and, once the chain is built, track it with
+/// [`MockClient::track_account`].
+///
+/// # Arguments
+/// * `seed` - Seed for both the account ID and its Falcon512 key, so wallets are
+///   reproducible
+/// * `assets` - The assets in the wallet's vault
+///
+/// # Returns
+/// The wallet account and its authentication key
+///
+/// # Errors
+/// Returns an error if the account cannot be built
+pub fn existing_wallet(
+    seed: [u8; 32],
+    assets: impl IntoIterator<Item = Asset>,
+) -> Result<(Account, AuthSecretKey)> {
+    let key = AuthSecretKey::new_falcon512_poseidon2_with_rng(&mut StdRng::from_seed(seed));
+    let account = AccountBuilder::new(seed)
+        .account_type(AccountType::Public)
+        .with_auth_component(AuthSingleSig::new(
+            key.public_key().to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(BasicWallet)
+        .with_assets(assets)
+        .build_existing()
+        .context("Failed to build existing wallet")?;
+    Ok((account, key))
+}
+
+/// Builds a public fungible faucet that can mint up to `max_supply` tokens, as if it had
+/// been deployed already
+///
+/// Only mint and burn policies are installed: transfer policies would require assets
+/// carrying callback flags, which `FungibleAsset::new` does not set. Add the faucet to the
+/// `MockChain` builder and track it like [`existing_wallet`].
+///
+/// # Arguments
+/// * `seed` - Seed for both the account ID and its Falcon512 key
+/// * `symbol` - The token symbol, also used as the token name
+/// * `max_supply` - The maximum number of tokens the faucet can issue
+///
+/// # Returns
+/// The faucet account and its authentication key
+///
+/// # Errors
+/// Returns an error if the token metadata is invalid or the account cannot be built
+pub fn existing_faucet(
+    seed: [u8; 32],
+    symbol: &str,
+    max_supply: u64,
+) -> Result<(Account, AuthSecretKey)> {
+    let key = AuthSecretKey::new_falcon512_poseidon2_with_rng(&mut StdRng::from_seed(seed));
+    let faucet = FungibleFaucet::builder()
+        .name(TokenName::new(symbol).context("Invalid token name")?)
+        .symbol(TokenSymbol::new(symbol).context("Invalid token symbol")?)
+        .decimals(8)
+        .max_supply(AssetAmount::new(max_supply).context("Invalid max supply")?)
+        .build()
+        .context("Failed to build fungible faucet component")?;
+    let policy_manager = TokenPolicyManager::new()
+        .with_mint_policy(MintPolicyConfig::AllowAll, PolicyRegistration::Active)?
+        .with_burn_policy(BurnPolicyConfig::AllowAll, PolicyRegistration::Active)?;
+    let account = AccountBuilder::new(seed)
+        .account_type(AccountType::Public)
+        .with_auth_component(AuthSingleSig::new(
+            key.public_key().to_commitment(),
+            AuthSchemeId::Falcon512Poseidon2,
+        ))
+        .with_component(faucet)
+        .with_components(policy_manager)
+        .build_existing()
+        .context("Failed to build existing faucet")?;
+    Ok((account, key))
+}
+
+/// Adds an existing counter account starting at `initial` to a mock chain
+///
+/// # Arguments
+/// * `builder` - The builder of the mock chain
+/// * `initial` - The initial counter value
+/// * `account_type` - Whether the counter is public or private
+///
+/// # Returns
+/// The counter account
+///
+/// # Errors
+/// Returns an error if the counter package cannot be built or the account cannot be added
+pub fn add_counter_account(
+    builder: &mut MockChainBuilder,
+    initial: u64,
+    account_type: AccountType,
+) -> Result<Account> {
+    let counter_component = AccountComponent::from_package(
+        counter_package()?.as_ref(),
+        &counter_init_storage_data(initial)?,
+    )
+    .context("Failed to build account component from counter package")?;
+    builder.add_account_from_builder(
+        Auth::BasicAuth {
+            auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+        },
+        AccountBuilder::new(COUNTER_ACCOUNT_SEED)
+            .account_type(account_type)
+            .with_component(counter_component),
+        AccountState::Exists,
+    )
+}
+
+/// Runs the custom transaction script of `request`, with its script argument, in a mock
+/// chain transaction
+///
+/// This lets tests execute the requests built by the helpers (e.g. `reset_counter_request`)
+/// against a `MockChain`.
+///
+/// # Errors
+/// Returns an error if `request` has no custom transaction script
+pub fn with_request_script(
+    tx_context: TransactionContextBuilder,
+    request: &TransactionRequest,
+) -> Result<TransactionContextBuilder> {
+    let Some(TransactionScriptTemplate::CustomScript(script)) = request.script_template() else {
+        bail!("Transaction request has no custom transaction script");
+    };
+    let tx_context = tx_context.tx_script(script.clone());
+    Ok(match request.script_arg() {
+        Some(script_arg) => tx_context.tx_script_args(*script_arg),
+        None => tx_context,
+    })
+}
+
+/// Asserts that the committed counter value of `counter_id` on `chain` equals `expected`
+///
+/// Map values are stored as scalar words in `[value, 0, 0, 0]` layout, so only the first
+/// element is compared.
+///
+/// # Arguments
+/// * `chain` - The mock chain holding the committed counter account
+/// * `counter_id` - The ID of the counter account
+/// * `expected` - The expected counter value
+///
+/// # Panics
+/// Panics if the counter account or its storage cannot be read, or if the value differs
+/// from `expected`
+pub fn assert_counter_eq(chain: &MockChain, counter_id: AccountId, expected: u64) {
+    let counter_storage_slot = counter_storage_slot().expect("invalid counter storage slot name");
+    let count = chain
+        .committed_account(counter_id)
+        .unwrap_or_else(|err| panic!("counter {} is not committed: {err}", counter_id.to_hex()))
+        .storage()
+        .get_map_item(&counter_storage_slot, COUNTER_STORAGE_KEY)
+        .unwrap_or_else(|err| {
+            panic!(
+                "failed to read counter value of {}: {err}",
+                counter_id.to_hex()
+            )
+        });
+
+    let actual = count[0].as_canonical_u64();
+    assert_eq!(
+        actual,
+        expected,
+        "expected {expected}, got {actual} for contract {}",
+        counter_id.to_hex()
+    );
+}
+
+/// Initializes an isolated, synced client for tests that talk to a node
+///
+/// The endpoint is read from `MIDEN_TEST_ENDPOINT` (e.g. `http://localhost:57291` for a
+/// local node in CI) and defaults to testnet. Each call gets an in-memory store and its own
+/// keystore directory under the system temp directory, so tests need no cleanup and can run
+/// in parallel.
+///
+/// # Returns
+/// A `ClientSetup` whose client has completed an initial sync
+///
+/// # Errors
+/// Returns an error if `MIDEN_TEST_ENDPOINT` is not a valid endpoint, or if client
+/// setup or the initial sync fails
+pub async fn setup_test_client() -> Result<ClientSetup> {
+    static NEXT_KEYSTORE_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let network = match std::env::var(TEST_ENDPOINT_ENV) {
+        Ok(endpoint) => Network::Custom(
+            Endpoint::try_from(endpoint.as_str())
+                .map_err(|err| anyhow!("Invalid {TEST_ENDPOINT_ENV} '{endpoint}': {err}"))?,
+        ),
+        Err(_) => Network::Testnet,
+    };
+
+    let keystore_id = NEXT_KEYSTORE_ID.fetch_add(1, Ordering::Relaxed);
+    let keystore_path = std::env::temp_dir().join(format!(
+        "miden-test-keystore-{}-{keystore_id}",
+        std::process::id()
+    ));
+
+    let (setup, _) = setup_client_with_retry(
+        ClientConfig {
+            network,
+            keystore_path,
+            store: StoreKind::InMemory,
+        },
+        DEFAULT_SYNC_ATTEMPTS,
+    )
+    .await?;
+
+    Ok(setup)
+}
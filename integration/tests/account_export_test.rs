@@ -0,0 +1,104 @@
+use integration::helpers::{
+    counter_init_storage_data, counter_package, counter_storage_slot, create_account_from_package,
+    create_basic_wallet_account, export_account, import_account_from_file,
+    setup_client_with_config, AccountCreationConfig, ClientConfig, ClientSetup, StoreKind,
+    WalletAuth, COUNTER_STORAGE_KEY,
+};
+use miden_client::{account::AccountType, keystore::Keystore};
+
+/// Returns a client with an in-memory store and a keystore unique to `name`.
+async fn offline_client(name: &str) -> anyhow::Result<(ClientSetup, std::path::PathBuf)> {
+    let keystore_path = std::env::temp_dir().join(format!(
+        "miden-account-export-{name}-{}",
+        std::process::id()
+    ));
+    let setup = setup_client_with_config(ClientConfig {
+        keystore_path: keystore_path.clone(),
+        store: StoreKind::InMemory,
+        ..Default::default()
+    })
+    .await?;
+    Ok((setup, keystore_path))
+}
+
+#[tokio::test]
+async fn private_accounts_survive_export_and_import() -> anyhow::Result<()> {
+    // Test that private account state and keys, which cannot be fetched from the node, are
+    // restored from an account file into a fresh store and keystore
+    let (
+        ClientSetup {
+            mut client,
+            keystore,
+        },
+        keystore_path,
+    ) = offline_client("source").await?;
+
+    let counter_storage_slot = counter_storage_slot()?;
+    let counter = create_account_from_package(
+        &mut client,
+        &keystore,
+        counter_package()?,
+        WalletAuth::NoAuth,
+        AccountCreationConfig {
+            account_type: AccountType::Private,
+            init_storage_data: counter_init_storage_data(7)?,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let wallet = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        AccountCreationConfig {
+            account_type: AccountType::Private,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let export_dir = unique_temp_dir("files")?;
+    let counter_file = export_dir.join("counter.mac");
+    let wallet_file = export_dir.join("wallet.mac");
+    export_account(&client, &keystore, counter.id(), &counter_file).await?;
+    export_account(&client, &keystore, wallet.id(), &wallet_file).await?;
+
+    let (
+        ClientSetup {
+            client: mut restored_client,
+            keystore: restored_keystore,
+        },
+        restored_keystore_path,
+    ) = offline_client("restored").await?;
+    let restored_counter =
+        import_account_from_file(&mut restored_client, &restored_keystore, &counter_file).await?;
+    import_account_from_file(&mut restored_client, &restored_keystore, &wallet_file).await?;
+
+    assert_eq!(restored_counter.to_commitment(), counter.to_commitment());
+    let count = restored_client
+        .try_get_account(counter.id())
+        .await?
+        .storage()
+        .get_map_item(&counter_storage_slot, COUNTER_STORAGE_KEY)?;
+    assert_eq!(count[0].as_canonical_u64(), 7);
+
+    let wallet_keys = restored_keystore.get_keys_for_account(&wallet.id()).await?;
+    assert_eq!(
+        wallet_keys,
+        keystore.get_keys_for_account(&wallet.id()).await?
+    );
+
+    for dir in [keystore_path, restored_keystore_path, export_dir] {
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+/// Creates a fresh directory for the exported account files.
+fn unique_temp_dir(name: &str) -> std::io::Result<std::path::PathBuf> {
+    let dir = std::env::temp_dir().join(format!(
+        "miden-account-export-{name}-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
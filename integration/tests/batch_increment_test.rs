@@ -0,0 +1,38 @@
+use integration::helpers::{
+    batch_increment_request, counter_delta, increment_counter_script_package,
+};
+use integration::testing::{add_counter_account, assert_counter_eq, with_request_script};
+use miden_client::account::AccountType;
+use miden_testing::MockChain;
+
+#[tokio::test]
+async fn batch_increment_test() -> anyhow::Result<()> {
+    // Test that the batch increment script advances the counter by 3 in one transaction
+    let mut builder = MockChain::builder();
+
+    // Build contracts
+    let increment_package = increment_counter_script_package()?;
+
+    // Create the counter account starting at 0
+    let counter_account = add_counter_account(&mut builder, 0, AccountType::Public)?;
+
+    // Build the mock chain
+    let mut mock_chain = builder.build()?;
+
+    // Run the batch increment script with 3 as the number of increments
+    let increment_request = batch_increment_request(&increment_package, 3)?;
+    let executed_transaction = with_request_script(
+        mock_chain.build_tx_context(counter_account.clone(), &[], &[])?,
+        &increment_request,
+    )?
+    .build()?
+    .execute()
+    .await?;
+    assert_eq!(counter_delta(&executed_transaction), Some(3));
+
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 3);
+    Ok(())
+}
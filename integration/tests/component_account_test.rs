@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use integration::{
+    helpers::{
+        counter_init_storage_data, counter_package, counter_storage_slot,
+        create_account_from_component, deploy_component, AccountCreationConfig, Network,
+        WalletAuth, COUNTER_STORAGE_KEY,
+    },
+    testing::MockClient,
+};
+use miden_client::{
+    account::{AccountComponent, AccountId},
+    auth::AuthSchemeId,
+    keystore::Keystore,
+};
+use miden_testing::MockChain;
+
+fn counter_component(initial: u64) -> anyhow::Result<AccountComponent> {
+    Ok(AccountComponent::from_package(
+        counter_package()?.as_ref(),
+        &counter_init_storage_data(initial)?,
+    )?)
+}
+
+#[tokio::test]
+async fn component_account_keeps_its_auth_key() -> anyhow::Result<()> {
+    // Test that a component account gets its key in the keystore only with SingleSig
+    let mut mock = MockClient::new(MockChain::new()).await?;
+
+    let signed = create_account_from_component(
+        &mut mock.client,
+        &mock.keystore,
+        counter_component(5)?,
+        WalletAuth::SingleSig(AuthSchemeId::Falcon512Poseidon2),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    let open = create_account_from_component(
+        &mut mock.client,
+        &mock.keystore,
+        counter_component(5)?,
+        WalletAuth::NoAuth,
+        AccountCreationConfig::default(),
+    )
+    .await?;
+
+    assert_eq!(
+        mock.keystore
+            .get_keys_for_account(&signed.id())
+            .await?
+            .len(),
+        1
+    );
+    assert!(mock
+        .keystore
+        .get_keys_for_account(&open.id())
+        .await
+        .is_err());
+    let count = signed
+        .storage()
+        .get_map_item(&counter_storage_slot()?, COUNTER_STORAGE_KEY)?;
+    assert_eq!(count[0].as_canonical_u64(), 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn deploy_component_with_single_sig() -> anyhow::Result<()> {
+    // Test that a component account requiring a signature is deployed on chain
+    let mut mock = MockClient::new(MockChain::new()).await?;
+    mock.prove_block().await?;
+
+    let record = {
+        // The deployment waits for its transaction to be committed.
+        let _block_producer = mock.produce_blocks(Duration::from_millis(500));
+        deploy_component(
+            &mut mock.client,
+            &mock.keystore,
+            &Network::Localhost,
+            "signed-counter".to_string(),
+            counter_component(0)?,
+            WalletAuth::SingleSig(AuthSchemeId::Falcon512Poseidon2),
+            AccountCreationConfig::default(),
+        )
+        .await?
+    };
+
+    let account_id = AccountId::from_hex(&record.contract_id)?;
+    let account = mock
+        .client
+        .get_account(account_id)
+        .await?
+        .expect("deployed account is tracked by the client");
+    assert!(account.nonce().as_canonical_u64() > 0);
+    assert!(record.block_num > 0);
+    assert_eq!(
+        mock.keystore.get_keys_for_account(&account_id).await?.len(),
+        1
+    );
+    Ok(())
+}
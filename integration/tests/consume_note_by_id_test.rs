@@ -0,0 +1,57 @@
+use integration::{
+    helpers::{consume_note_by_id, create_p2id_note, fungible_balance},
+    testing::{existing_wallet, MockClient},
+};
+use miden_client::{
+    asset::{Asset, FungibleAsset},
+    auth::AuthSchemeId,
+    note::NoteAssets,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn consume_public_note_created_by_another_client() -> anyhow::Result<()> {
+    // Test that bob's client consumes a public note published by alice's client, knowing
+    // only the note ID
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_faucet(
+        Auth::BasicAuth {
+            auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+        },
+        "TST",
+        1_000,
+        None,
+    )?;
+    let asset = FungibleAsset::new(faucet.id(), 100)?;
+
+    let (alice, alice_key) = existing_wallet([1_u8; 32], [Asset::from(asset)])?;
+    let (bob, bob_key) = existing_wallet([2_u8; 32], [])?;
+    builder.add_account(alice.clone())?;
+    builder.add_account(bob.clone())?;
+
+    let mut alice_client = MockClient::new(builder.build()?).await?;
+    let mut bob_client = alice_client.connect_peer().await?;
+    alice_client.track_account(&alice, Some(&alice_key)).await?;
+    bob_client.track_account(&bob, Some(&bob_key)).await?;
+    alice_client.prove_block().await?;
+    bob_client.client.sync_state().await?;
+
+    let (p2id_note, _) = create_p2id_note(
+        &mut alice_client.client,
+        alice.id(),
+        bob.id(),
+        NoteAssets::new(vec![asset.into()])?,
+    )
+    .await?;
+    alice_client.prove_block().await?;
+    bob_client.client.sync_state().await?;
+
+    consume_note_by_id(&mut bob_client.client, bob.id(), p2id_note.id()).await?;
+    bob_client.prove_block().await?;
+
+    assert_eq!(
+        fungible_balance(&bob_client.client, bob.id(), faucet.id()).await?,
+        100
+    );
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use std::{path::Path, sync::Arc};
-
 use anyhow::Context;
-use integration::helpers::{build_project_in_dir, counter_storage_slot, COUNTER_STORAGE_KEY};
+use integration::helpers::{
+    counter_delta, counter_package, counter_storage_slot, create_note_from_package,
+    increment_note_config, increment_note_package, COUNTER_STORAGE_KEY,
+};
+use integration::testing::assert_counter_eq;
 use miden_client::{
     account::{component::InitStorageData, AccountBuilder, AccountComponent, AccountType},
     auth::AuthSchemeId,
@@ -10,7 +12,6 @@ use miden_client::{
     transaction::RawOutputNote,
     Word,
 };
-use miden_standards::testing::note::NoteBuilder;
 use miden_testing::{AccountState, Auth, MockChain};
 
 #[tokio::test]
@@ -24,14 +25,8 @@ async fn counter_test() -> anyhow::Result<()> {
     })?;
 
     // Build contracts
-    let contract_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/counter-account"),
-        true,
-    )?);
-    let note_package = Arc::new(build_project_in_dir(
-        Path::new("../contracts/increment-note"),
-        true,
-    )?);
+    let contract_package = counter_package()?;
+    let note_package = increment_note_package()?;
 
     // Create the counter account with its initial storage through the component schema.
     let counter_storage_slot = counter_storage_slot()?;
@@ -55,10 +50,13 @@ async fn counter_test() -> anyhow::Result<()> {
             .context("failed to build note script from package")?
             .root(),
     ));
-    let counter_note = NoteBuilder::new(sender.id(), &mut note_rng)
-        .package((*note_package).clone())
-        .build()
-        .context("failed to build counter note from package")?;
+    let counter_note = create_note_from_package(
+        sender.id(),
+        &note_package,
+        &mut note_rng,
+        increment_note_config(1)?,
+    )
+    .context("failed to build counter note from package")?;
 
     // add counter account and note to mockchain
     builder.add_output_note(RawOutputNote::Full(counter_note.clone()));
@@ -73,23 +71,12 @@ async fn counter_test() -> anyhow::Result<()> {
 
     // Execute the transaction
     let executed_transaction = tx_context.execute().await?;
+    assert_eq!(counter_delta(&executed_transaction), Some(1));
 
     // Add the executed transaction to the mockchain
     mock_chain.add_pending_executed_transaction(&executed_transaction)?;
     mock_chain.prove_next_block()?;
 
-    // Get the count from the updated counter account
-    let count = mock_chain
-        .committed_account(counter_account.id())?
-        .storage()
-        .get_map_item(&counter_storage_slot, COUNTER_STORAGE_KEY)
-        .expect("Failed to get counter value from storage slot");
-
-    // Map values are returned as scalar words in `[value, 0, 0, 0]` layout.
-    assert_eq!(
-        count[0].as_canonical_u64(),
-        1,
-        "Count value is not equal to 1"
-    );
+    assert_counter_eq(&mock_chain, counter_account.id(), 1);
     Ok(())
 }
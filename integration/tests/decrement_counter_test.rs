@@ -0,0 +1,49 @@
+use integration::helpers::{decrement_counter_request, decrement_counter_script_package};
+use integration::testing::{add_counter_account, assert_counter_eq, with_request_script};
+use miden_client::account::AccountType;
+use miden_testing::MockChain;
+
+#[tokio::test]
+async fn decrement_counter_test() -> anyhow::Result<()> {
+    // Test that decrementing a counter at 1 yields 0, and decrementing it again fails
+    let mut builder = MockChain::builder();
+
+    // Build contracts
+    let decrement_package = decrement_counter_script_package()?;
+
+    // Create the counter account starting at 1
+    let counter_account = add_counter_account(&mut builder, 1, AccountType::Public)?;
+
+    // Build the mock chain
+    let mut mock_chain = builder.build()?;
+
+    let decrement_request = decrement_counter_request(&decrement_package)?;
+
+    // Decrement the counter from 1 to 0
+    let account = mock_chain.committed_account(counter_account.id())?.clone();
+    let executed_transaction = with_request_script(
+        mock_chain.build_tx_context(account, &[], &[])?,
+        &decrement_request,
+    )?
+    .build()?
+    .execute()
+    .await?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 0);
+
+    // Decrementing the zero counter must fail instead of wrapping around
+    let account = mock_chain.committed_account(counter_account.id())?.clone();
+    let result = with_request_script(
+        mock_chain.build_tx_context(account, &[], &[])?,
+        &decrement_request,
+    )?
+    .build()?
+    .execute()
+    .await;
+    assert!(result.is_err(), "decrementing a zero counter must fail");
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 0);
+    Ok(())
+}
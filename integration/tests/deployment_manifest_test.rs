@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use integration::{
+    helpers::{
+        counter_init_storage_data, counter_package, deploy_many, find_deployment,
+        read_deployment_manifest, record_deployment, AccountCreationConfig, ContractSpec,
+        DeploymentRecord, Network, WalletAuth,
+    },
+    testing::MockClient,
+};
+use miden_client::account::AccountId;
+use miden_testing::MockChain;
+
+fn record(name: &str, contract_id: &str) -> DeploymentRecord {
+    DeploymentRecord {
+        name: name.to_string(),
+        contract_id: contract_id.to_string(),
+        contract_address: "mtst1example".to_string(),
+        initial_commitment: "0x00".to_string(),
+        network: "testnet".to_string(),
+        block_num: 1,
+        tx_id: "0x01".to_string(),
+    }
+}
+
+#[test]
+fn deployment_manifest_round_trip() -> anyhow::Result<()> {
+    // Test that recording deployments keeps one entry per name and reads back by name
+    let path = std::env::temp_dir().join(format!(
+        "miden-deployment-manifest-{}.json",
+        std::process::id()
+    ));
+
+    record_deployment(&path, record("counter-account", "0xaa"))?;
+    record_deployment(&path, record("other-contract", "0xbb"))?;
+    record_deployment(&path, record("counter-account", "0xcc"))?;
+
+    let manifest = read_deployment_manifest(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(manifest.len(), 2);
+    assert_eq!(
+        find_deployment(&manifest, "counter-account").map(|entry| entry.contract_id.as_str()),
+        Some("0xcc")
+    );
+    assert_eq!(
+        find_deployment(&manifest, "other-contract").map(|entry| entry.contract_id.as_str()),
+        Some("0xbb")
+    );
+    assert!(find_deployment(&manifest, "missing").is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn deploy_many_records_every_contract() -> anyhow::Result<()> {
+    // Test that deploying two contracts records both in the manifest
+    let path = std::env::temp_dir().join(format!(
+        "miden-deploy-many-manifest-{}.json",
+        std::process::id()
+    ));
+    let specs = ["first-counter", "second-counter"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            Ok(ContractSpec {
+                name: name.to_string(),
+                package: counter_package()?,
+                auth: WalletAuth::NoAuth,
+                config: AccountCreationConfig {
+                    init_seed: Some([index as u8 + 1; 32]),
+                    init_storage_data: counter_init_storage_data(0)?,
+                    ..Default::default()
+                },
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut mock = MockClient::new(MockChain::new()).await?;
+    mock.prove_block().await?;
+    let records = {
+        // Each deployment waits for its transaction to be committed.
+        let _block_producer = mock.produce_blocks(Duration::from_millis(500));
+        deploy_many(
+            &mut mock.client,
+            &mock.keystore,
+            &Network::Localhost,
+            specs,
+            &path,
+        )
+        .await?
+    };
+
+    let manifest = read_deployment_manifest(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(manifest, records);
+    for (name, record) in ["first-counter", "second-counter"].iter().zip(&records) {
+        let entry = find_deployment(&manifest, name)
+            .unwrap_or_else(|| panic!("{name} is missing from the manifest"));
+        assert_eq!(entry, record);
+        let account = mock
+            .client
+            .get_account(AccountId::from_hex(&record.contract_id)?)
+            .await?
+            .unwrap_or_else(|| panic!("{name} is not tracked by the client"));
+        assert!(
+            account.nonce().as_canonical_u64() > 0,
+            "{name} was not deployed"
+        );
+    }
+    assert_ne!(records[0].contract_id, records[1].contract_id);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use integration::{
+    helpers::{create_wallet_with_assets, fungible_balance},
+    testing::{existing_faucet, MockClient},
+};
+use miden_testing::MockChain;
+
+#[tokio::test]
+async fn wallet_is_funded_from_faucet() -> anyhow::Result<()> {
+    // Test that the wallet created by create_wallet_with_assets holds the minted tokens
+    let mut builder = MockChain::builder();
+    let (faucet, faucet_key) = existing_faucet([4_u8; 32], "TST", 1_000)?;
+    builder.add_account(faucet.clone())?;
+
+    let mut mock = MockClient::new(builder.build()?).await?;
+    mock.track_account(&faucet, Some(&faucet_key)).await?;
+    mock.prove_block().await?;
+
+    let wallet = {
+        // The helper waits for the mint and consume transactions to be committed.
+        let _block_producer = mock.produce_blocks(Duration::from_millis(500));
+        create_wallet_with_assets(&mut mock.client, mock.keystore.clone(), faucet.id(), 100).await?
+    };
+    mock.prove_block().await?;
+
+    assert_eq!(
+        fungible_balance(&mock.client, wallet.id(), faucet.id()).await?,
+        100
+    );
+    Ok(())
+}
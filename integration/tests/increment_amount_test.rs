@@ -0,0 +1,61 @@
+use anyhow::Context;
+use integration::helpers::{
+    create_note_from_package, increment_note_config, increment_note_package,
+};
+use integration::testing::{add_counter_account, assert_counter_eq};
+use miden_client::{
+    account::AccountType, auth::AuthSchemeId, crypto::RandomCoin, note::NoteScript,
+    transaction::RawOutputNote, Word,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn increment_amount_test() -> anyhow::Result<()> {
+    // Test that an increment note carrying an amount of 5 in its storage adds 5 to the counter
+    let mut builder = MockChain::builder();
+
+    // Create note sender account
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    // Build contracts
+    let note_package = increment_note_package()?;
+
+    // Create the counter account starting at 0
+    let counter_account = add_counter_account(&mut builder, 0, AccountType::Public)?;
+
+    let mut note_rng = RandomCoin::new(Word::from(
+        NoteScript::from_package(note_package.as_ref())
+            .context("failed to build note script from package")?
+            .root(),
+    ));
+    let counter_note = create_note_from_package(
+        sender.id(),
+        &note_package,
+        &mut note_rng,
+        increment_note_config(5)?,
+    )
+    .context("failed to build counter note from package")?;
+
+    // add counter account and note to mockchain
+    builder.add_output_note(RawOutputNote::Full(counter_note.clone()));
+
+    // Build the mock chain
+    let mut mock_chain = builder.build()?;
+
+    // Build the transaction context
+    let tx_context = mock_chain
+        .build_tx_context(counter_account.clone(), &[counter_note.id()], &[])?
+        .build()?;
+
+    // Execute the transaction
+    let executed_transaction = tx_context.execute().await?;
+
+    // Add the executed transaction to the mockchain
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 5);
+    Ok(())
+}
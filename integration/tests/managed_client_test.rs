@@ -0,0 +1,47 @@
+use integration::helpers::{
+    create_basic_wallet_account, AccountCreationConfig, ClientConfig, ManagedClient, Network,
+    StoreKind,
+};
+use miden_client::rpc::Endpoint;
+
+/// Returns an endpoint on localhost that no node listens on.
+fn unreachable_endpoint(port: u16) -> Endpoint {
+    Endpoint::new("http".to_string(), "localhost".to_string(), Some(port))
+}
+
+#[tokio::test]
+async fn failover_keeps_store_and_keystore() -> anyhow::Result<()> {
+    // Test that switching endpoints keeps the accounts tracked by the client
+    let keystore_path = std::env::temp_dir().join(format!(
+        "miden-managed-client-keystore-{}",
+        std::process::id()
+    ));
+    let mut managed = ManagedClient::new(
+        ClientConfig {
+            network: Network::Custom(unreachable_endpoint(1)),
+            keystore_path: keystore_path.clone(),
+            store: StoreKind::InMemory,
+        },
+        vec![unreachable_endpoint(2)],
+    )
+    .await?;
+
+    let keystore = managed.keystore();
+    let wallet = create_basic_wallet_account(
+        managed.client_mut(),
+        keystore,
+        AccountCreationConfig::default(),
+    )
+    .await?;
+
+    let endpoint = managed.failover().await?.clone();
+    assert_eq!(endpoint, unreachable_endpoint(2));
+    assert!(managed.client().get_account(wallet.id()).await?.is_some());
+
+    // Every endpoint is down, so the sync fails after trying each of them once
+    assert!(managed.sync_with_retry(1).await.is_err());
+    assert_eq!(managed.endpoint(), &unreachable_endpoint(1));
+
+    std::fs::remove_dir_all(keystore_path)?;
+    Ok(())
+}
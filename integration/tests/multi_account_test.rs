@@ -0,0 +1,194 @@
+use integration::{
+    helpers::{
+        consume_notes, create_basic_wallet_account, create_p2id_note, create_wallet_with_auth,
+        fungible_balance, setup_client_with_config, AccountCreationConfig, ClientConfig,
+        ClientSetup, StoreKind, WalletAuth,
+    },
+    testing::{existing_wallet, MockClient},
+};
+use miden_client::{
+    asset::{Asset, FungibleAsset},
+    auth::AuthSchemeId,
+    keystore::Keystore,
+    note::NoteAssets,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn wallets_share_keystore() -> anyhow::Result<()> {
+    // Creating several wallets with one keystore keeps a separate key per account
+    let keystore_path = std::env::temp_dir().join(format!(
+        "miden-multi-account-keystore-{}",
+        std::process::id()
+    ));
+    let ClientSetup {
+        mut client,
+        keystore,
+    } = setup_client_with_config(ClientConfig {
+        keystore_path: keystore_path.clone(),
+        store: StoreKind::InMemory,
+        ..Default::default()
+    })
+    .await?;
+
+    let alice = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    let bob = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    assert_ne!(alice.id(), bob.id());
+
+    let alice_keys = keystore.get_keys_for_account(&alice.id()).await?;
+    let bob_keys = keystore.get_keys_for_account(&bob.id()).await?;
+    assert_eq!(alice_keys.len(), 1);
+    assert_eq!(bob_keys.len(), 1);
+    assert_ne!(
+        alice_keys[0].public_key().to_commitment(),
+        bob_keys[0].public_key().to_commitment()
+    );
+
+    std::fs::remove_dir_all(keystore_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_key_does_not_derive_from_id_seed() -> anyhow::Result<()> {
+    // Test that the public ID seed alone does not determine the wallet key, while both seeds
+    // together reproduce the wallet
+    let keystore_path = std::env::temp_dir().join(format!(
+        "miden-seeded-wallet-keystore-{}",
+        std::process::id()
+    ));
+    let ClientSetup {
+        mut client,
+        keystore,
+    } = setup_client_with_config(ClientConfig {
+        keystore_path: keystore_path.clone(),
+        store: StoreKind::InMemory,
+        ..Default::default()
+    })
+    .await?;
+
+    let seeded_config = |key_seed| AccountCreationConfig {
+        init_seed: Some([1_u8; 32]),
+        key_seed,
+        ..Default::default()
+    };
+    let first = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        seeded_config(Some([2_u8; 32])),
+    )
+    .await?;
+    let reproduced = create_basic_wallet_account(
+        &mut client,
+        keystore.clone(),
+        seeded_config(Some([2_u8; 32])),
+    )
+    .await?;
+    let random_key =
+        create_basic_wallet_account(&mut client, keystore.clone(), seeded_config(None)).await?;
+
+    assert_eq!(first.id(), reproduced.id());
+    assert_ne!(first.id(), random_key.id());
+
+    std::fs::remove_dir_all(keystore_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallets_with_custom_auth() -> anyhow::Result<()> {
+    // Test that the chosen auth component decides which key, if any, is stored
+    let keystore_path =
+        std::env::temp_dir().join(format!("miden-wallet-auth-keystore-{}", std::process::id()));
+    let ClientSetup {
+        mut client,
+        keystore,
+    } = setup_client_with_config(ClientConfig {
+        keystore_path: keystore_path.clone(),
+        store: StoreKind::InMemory,
+        ..Default::default()
+    })
+    .await?;
+
+    let ecdsa_wallet = create_wallet_with_auth(
+        &mut client,
+        keystore.clone(),
+        WalletAuth::SingleSig(AuthSchemeId::EcdsaK256Keccak),
+        AccountCreationConfig::default(),
+    )
+    .await?;
+    let open_wallet = create_wallet_with_auth(
+        &mut client,
+        keystore.clone(),
+        WalletAuth::NoAuth,
+        AccountCreationConfig::default(),
+    )
+    .await?;
+
+    let ecdsa_keys = keystore.get_keys_for_account(&ecdsa_wallet.id()).await?;
+    assert_eq!(ecdsa_keys.len(), 1);
+    assert_eq!(ecdsa_keys[0].auth_scheme(), AuthSchemeId::EcdsaK256Keccak);
+    // The keystore knows nothing about accounts without an auth key
+    assert!(keystore
+        .get_keys_for_account(&open_wallet.id())
+        .await
+        .is_err());
+
+    std::fs::remove_dir_all(keystore_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn p2id_note_between_wallets() -> anyhow::Result<()> {
+    // Test that bob receives the assets of a P2ID note sent to him by alice
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_faucet(
+        Auth::BasicAuth {
+            auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+        },
+        "TST",
+        1_000,
+        None,
+    )?;
+    let asset = FungibleAsset::new(faucet.id(), 100)?;
+
+    let (alice, alice_key) = existing_wallet([1_u8; 32], [Asset::from(asset)])?;
+    let (bob, bob_key) = existing_wallet([2_u8; 32], [])?;
+    builder.add_account(alice.clone())?;
+    builder.add_account(bob.clone())?;
+
+    let mut mock = MockClient::new(builder.build()?).await?;
+    mock.track_account(&alice, Some(&alice_key)).await?;
+    mock.track_account(&bob, Some(&bob_key)).await?;
+    mock.prove_block().await?;
+
+    let (p2id_note, _) = create_p2id_note(
+        &mut mock.client,
+        alice.id(),
+        bob.id(),
+        NoteAssets::new(vec![asset.into()])?,
+    )
+    .await?;
+    mock.prove_block().await?;
+
+    consume_notes(&mut mock.client, bob.id(), vec![(p2id_note, None)]).await?;
+    mock.prove_block().await?;
+
+    assert_eq!(
+        fungible_balance(&mock.client, bob.id(), faucet.id()).await?,
+        100
+    );
+    assert_eq!(
+        fungible_balance(&mock.client, alice.id(), faucet.id()).await?,
+        0
+    );
+    Ok(())
+}
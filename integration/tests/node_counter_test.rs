@@ -0,0 +1,44 @@
+use integration::helpers::{
+    consume_notes, counter_init_storage_data, counter_package, create_account_from_package,
+    create_basic_wallet_account, increment_note_package, publish_note, wait_for_tx,
+    AccountCreationConfig, ClientSetup, CounterClient, WaitConfig, WalletAuth,
+};
+use integration::testing::setup_test_client;
+
+#[tokio::test]
+#[ignore = "needs a running node; set MIDEN_TEST_ENDPOINT or run against testnet"]
+async fn counter_increments_on_node() -> anyhow::Result<()> {
+    let ClientSetup {
+        mut client,
+        keystore,
+    } = setup_test_client().await?;
+    let wait_config = WaitConfig::default();
+
+    let counter_account = create_account_from_package(
+        &mut client,
+        &keystore,
+        counter_package()?,
+        WalletAuth::NoAuth,
+        AccountCreationConfig {
+            init_storage_data: counter_init_storage_data(0)?,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let sender =
+        create_basic_wallet_account(&mut client, keystore.clone(), Default::default()).await?;
+
+    let counter = CounterClient::new(counter_account.id(), increment_note_package()?)?;
+    let note = counter.increment_note(sender.id(), client.rng(), 1)?;
+
+    let publish_tx_id = publish_note(&mut client, sender.id(), note.clone()).await?;
+    wait_for_tx(&mut client, publish_tx_id, &wait_config).await?;
+
+    let consume_tx_id =
+        consume_notes(&mut client, counter_account.id(), vec![(note, None)]).await?;
+    wait_for_tx(&mut client, consume_tx_id, &wait_config).await?;
+
+    assert_eq!(counter.counter_value(&client).await?, 1);
+
+    Ok(())
+}
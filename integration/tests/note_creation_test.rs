@@ -0,0 +1,65 @@
+use integration::helpers::{
+    create_note_from_package, increment_note_package, network_note_config, NoteCreationConfig,
+};
+use miden_client::{
+    auth::AuthSchemeId, block::BlockNumber, crypto::RandomCoin, note::NoteTag, Felt, Word,
+};
+use miden_standards::note::{NetworkAccountTarget, NoteExecutionHint};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn note_creation_rejects_oversized_storage() -> anyhow::Result<()> {
+    // Test that note storage above the protocol limit is reported as an error instead of panicking
+    let mut builder = MockChain::builder();
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    let note_package = increment_note_package()?;
+
+    // Notes hold at most 1024 storage items.
+    let config = NoteCreationConfig {
+        note_storage: vec![Felt::ONE; 1025],
+        ..Default::default()
+    };
+    let result = create_note_from_package(
+        sender.id(),
+        &note_package,
+        RandomCoin::new(Word::empty()),
+        config,
+    );
+
+    assert!(result.is_err(), "Oversized note storage was accepted");
+    Ok(())
+}
+
+#[tokio::test]
+async fn network_note_carries_execution_hint() -> anyhow::Result<()> {
+    // Test that a network note created with an execution hint carries it in its attachment
+    let mut builder = MockChain::builder();
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+    let target = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    let note_package = increment_note_package()?;
+
+    let hint = NoteExecutionHint::after_block(BlockNumber::from(5_u32));
+    let note = create_note_from_package(
+        sender.id(),
+        &note_package,
+        RandomCoin::new(Word::empty()),
+        network_note_config(target.id(), hint)?,
+    )?;
+
+    let network_target = NetworkAccountTarget::try_from(note.attachments())?;
+    assert_eq!(network_target.target_id(), target.id());
+    assert_eq!(network_target.execution_hint(), hint);
+    assert_eq!(
+        note.metadata().tag(),
+        NoteTag::with_account_target(target.id())
+    );
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use integration::helpers::{parse_account_id, Network};
+use miden_client::{address::NetworkId, auth::AuthSchemeId};
+use miden_testing::{Auth, MockChain};
+
+#[test]
+fn parse_account_id_checks_network() -> anyhow::Result<()> {
+    // Test that IDs are accepted only for the network they are encoded for
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+    let account_id = account.id();
+    let testnet_id = account_id.to_bech32(NetworkId::Testnet);
+    let mainnet_id = account_id.to_bech32(NetworkId::Mainnet);
+
+    assert_eq!(
+        parse_account_id(&testnet_id, &Network::Testnet)?,
+        account_id
+    );
+
+    let err = parse_account_id(&mainnet_id, &Network::Testnet).unwrap_err();
+    assert!(err.to_string().contains(&mainnet_id));
+
+    let err = parse_account_id("not-an-account-id", &Network::Testnet).unwrap_err();
+    assert!(err.to_string().contains("not-an-account-id"));
+    Ok(())
+}
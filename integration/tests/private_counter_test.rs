@@ -0,0 +1,68 @@
+use anyhow::Context;
+use integration::helpers::{
+    counter_storage_slot, create_note_from_package, increment_note_config, increment_note_package,
+    COUNTER_STORAGE_KEY,
+};
+use integration::testing::add_counter_account;
+use miden_client::{
+    account::AccountType, auth::AuthSchemeId, crypto::RandomCoin, note::NoteScript,
+    transaction::RawOutputNote, Word,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn private_counter_test() -> anyhow::Result<()> {
+    // Test that a private counter is incremented by an unauthenticated increment note
+    let mut builder = MockChain::builder();
+
+    // Create note sender account
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    // Build contracts
+    let note_package = increment_note_package()?;
+
+    // Create the counter account starting at 0
+    let counter_account = add_counter_account(&mut builder, 0, AccountType::Private)?;
+
+    let mut note_rng = RandomCoin::new(Word::from(
+        NoteScript::from_package(note_package.as_ref())
+            .context("failed to build note script from package")?
+            .root(),
+    ));
+    let counter_note = create_note_from_package(
+        sender.id(),
+        &note_package,
+        &mut note_rng,
+        increment_note_config(1)?,
+    )
+    .context("failed to build counter note from package")?;
+    builder.add_output_note(RawOutputNote::Full(counter_note.clone()));
+
+    let mut mock_chain = builder.build()?;
+
+    // Pass the note as an unauthenticated input note, as a client without its inclusion
+    // proof would; the block authenticates it against the chain.
+    let tx_context = mock_chain
+        .build_tx_context(counter_account.clone(), &[], &[counter_note])?
+        .build()?;
+    let executed_transaction = tx_context.execute().await?;
+
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    // The chain only stores the commitment of a private account, so the new state is
+    // derived locally from the transaction's account delta.
+    let mut counter_state = counter_account.clone();
+    counter_state.apply_delta(executed_transaction.account_delta())?;
+    assert_eq!(
+        counter_state.to_commitment(),
+        mock_chain.account_tree().get(counter_account.id())
+    );
+    let count = counter_state
+        .storage()
+        .get_map_item(&counter_storage_slot()?, COUNTER_STORAGE_KEY)?;
+    assert_eq!(count[0].as_canonical_u64(), 1);
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use integration::helpers::{setup_read_only_client, ClientConfig, StoreKind};
+
+#[tokio::test]
+async fn read_only_client_creates_no_keystore() -> anyhow::Result<()> {
+    // Test that a read-only client builds without creating its keystore directory
+    let keystore_path =
+        std::env::temp_dir().join(format!("miden-read-only-keystore-{}", std::process::id()));
+
+    let client = setup_read_only_client(ClientConfig {
+        keystore_path: keystore_path.clone(),
+        store: StoreKind::InMemory,
+        ..Default::default()
+    })
+    .await?;
+
+    assert!(client.get_account_headers().await?.is_empty());
+    assert!(!keystore_path.exists());
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anyhow::Context;
+use integration::helpers::{
+    create_note_from_package, increment_note_config, increment_note_package, reset_counter_request,
+    reset_counter_script_package,
+};
+use integration::testing::{add_counter_account, assert_counter_eq, with_request_script};
+use miden_client::{
+    account::AccountType, auth::AuthSchemeId, crypto::RandomCoin, note::NoteScript,
+    transaction::RawOutputNote, Word,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn reset_counter_test() -> anyhow::Result<()> {
+    // Test that after incrementing the counter twice, the reset script sets it back to zero
+    let mut builder = MockChain::builder();
+
+    // Create note sender account
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    // Build contracts
+    let note_package = increment_note_package()?;
+    let reset_package = reset_counter_script_package()?;
+
+    // Create the counter account starting at 0
+    let counter_account = add_counter_account(&mut builder, 0, AccountType::Public)?;
+
+    // Create two increment notes and add them to the mockchain
+    let mut note_rng = RandomCoin::new(Word::from(
+        NoteScript::from_package(note_package.as_ref())
+            .context("failed to build note script from package")?
+            .root(),
+    ));
+    let mut increment_notes = Vec::new();
+    for _ in 0..2 {
+        let note = create_note_from_package(
+            sender.id(),
+            &note_package,
+            &mut note_rng,
+            increment_note_config(1)?,
+        )
+        .context("failed to build counter note from package")?;
+        builder.add_output_note(RawOutputNote::Full(note.clone()));
+        increment_notes.push(note);
+    }
+
+    // Build the mock chain
+    let mut mock_chain = builder.build()?;
+
+    // Consume each increment note in its own transaction
+    for note in &increment_notes {
+        let account = mock_chain.committed_account(counter_account.id())?.clone();
+        let executed_transaction = mock_chain
+            .build_tx_context(account, &[note.id()], &[])?
+            .build()?
+            .execute()
+            .await?;
+        mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+        mock_chain.prove_next_block()?;
+    }
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 2);
+
+    // Reset the counter to zero through the reset transaction script
+    let reset_request = reset_counter_request(&reset_package, 0)?;
+    let account = mock_chain.committed_account(counter_account.id())?.clone();
+    let executed_transaction = with_request_script(
+        mock_chain.build_tx_context(account, &[], &[])?,
+        &reset_request,
+    )?
+    .build()?
+    .execute()
+    .await?;
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    assert_counter_eq(&mock_chain, counter_account.id(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn reset_rejected_alongside_note() -> anyhow::Result<()> {
+    // Test that the reset script fails when the transaction also consumes a note
+    let mut builder = MockChain::builder();
+
+    let sender = builder.add_existing_wallet(Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    })?;
+
+    let note_package = increment_note_package()?;
+    let reset_package = reset_counter_script_package()?;
+
+    // Create the counter account starting at 0
+    let counter_account = add_counter_account(&mut builder, 0, AccountType::Public)?;
+
+    let mut note_rng = RandomCoin::new(Word::from(
+        NoteScript::from_package(note_package.as_ref())
+            .context("failed to build note script from package")?
+            .root(),
+    ));
+    let note = create_note_from_package(
+        sender.id(),
+        &note_package,
+        &mut note_rng,
+        increment_note_config(1)?,
+    )
+    .context("failed to build counter note from package")?;
+    builder.add_output_note(RawOutputNote::Full(note.clone()));
+
+    let mock_chain = builder.build()?;
+
+    // Consume the increment note and run the reset script in the same transaction
+    let reset_request = reset_counter_request(&reset_package, 0)?;
+    let result = with_request_script(
+        mock_chain.build_tx_context(counter_account, &[note.id()], &[])?,
+        &reset_request,
+    )?
+    .build()?
+    .execute()
+    .await;
+
+    assert!(
+        result.is_err(),
+        "reset must fail in a transaction that consumes notes"
+    );
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use integration::{
+    helpers::{consume_notes, consume_swap_note, create_swap_note, fungible_balance},
+    testing::{existing_wallet, MockClient},
+};
+use miden_client::{
+    asset::{Asset, FungibleAsset},
+    auth::AuthSchemeId,
+    note::{Note, NoteType},
+    store::NoteFilter,
+};
+use miden_testing::{Auth, MockChain};
+
+#[tokio::test]
+async fn swap_note_exchanges_assets() -> anyhow::Result<()> {
+    // Test that alice's offered asset and bob's requested asset change hands through a
+    // SWAP note and its payback note
+    let mut builder = MockChain::builder();
+    let auth = Auth::BasicAuth {
+        auth_scheme: AuthSchemeId::Falcon512Poseidon2,
+    };
+    let faucet_a = builder.add_existing_basic_faucet(auth.clone(), "TKA", 1_000, None)?;
+    let faucet_b = builder.add_existing_basic_faucet(auth, "TKB", 1_000, None)?;
+    let offered = FungibleAsset::new(faucet_a.id(), 100)?;
+    let requested = FungibleAsset::new(faucet_b.id(), 50)?;
+
+    let (alice, alice_key) = existing_wallet([1_u8; 32], [Asset::from(offered)])?;
+    let (bob, bob_key) = existing_wallet([2_u8; 32], [Asset::from(requested)])?;
+    builder.add_account(alice.clone())?;
+    builder.add_account(bob.clone())?;
+
+    let mut mock = MockClient::new(builder.build()?).await?;
+    mock.track_account(&alice, Some(&alice_key)).await?;
+    mock.track_account(&bob, Some(&bob_key)).await?;
+    mock.prove_block().await?;
+
+    // Alice moves the offered asset into a SWAP note
+    let (swap_note, payback_note, _) = create_swap_note(
+        &mut mock.client,
+        alice.id(),
+        offered.into(),
+        requested.into(),
+        NoteType::Public,
+    )
+    .await?;
+    mock.prove_block().await?;
+    assert_eq!(
+        fungible_balance(&mock.client, alice.id(), faucet_a.id()).await?,
+        0
+    );
+
+    // Bob consumes it, receiving the offered asset and paying alice back
+    consume_swap_note(&mut mock.client, bob.id(), swap_note, &payback_note).await?;
+    mock.prove_block().await?;
+    assert_eq!(
+        fungible_balance(&mock.client, bob.id(), faucet_a.id()).await?,
+        100
+    );
+    assert_eq!(
+        fungible_balance(&mock.client, bob.id(), faucet_b.id()).await?,
+        0
+    );
+
+    // Alice consumes the payback note, receiving the requested asset
+    let payback_record = mock
+        .client
+        .get_input_notes(NoteFilter::Committed)
+        .await?
+        .into_iter()
+        .find(|record| record.details_commitment() == payback_note.commitment())
+        .expect("payback note is tracked as an expected note");
+    let payback: Note = payback_record.try_into()?;
+    consume_notes(&mut mock.client, alice.id(), vec![(payback, None)]).await?;
+    mock.prove_block().await?;
+    assert_eq!(
+        fungible_balance(&mock.client, alice.id(), faucet_b.id()).await?,
+        50
+    );
+    Ok(())
+}
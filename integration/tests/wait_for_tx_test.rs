@@ -0,0 +1,34 @@
+use std::time::{Duration, Instant};
+
+use integration::{
+    helpers::{wait_for_tx, UnknownTransaction, WaitConfig},
+    testing::MockClient,
+};
+use miden_client::{transaction::TransactionId, Felt, Word};
+use miden_testing::MockChain;
+
+#[tokio::test]
+async fn wait_for_untracked_transaction_fails_fast() -> anyhow::Result<()> {
+    // Test that waiting for a transaction the client never submitted fails with
+    // UnknownTransaction instead of waiting out the timeout
+    let mut mock = MockClient::new(MockChain::new()).await?;
+    let tx_id = TransactionId::from_raw(Word::new([Felt::ONE; 4]));
+    let config = WaitConfig {
+        timeout: Duration::from_secs(60),
+        poll_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let err = wait_for_tx(&mut mock.client, tx_id, &config)
+        .await
+        .expect_err("an untracked transaction must not be reported as committed");
+
+    assert_eq!(
+        err.downcast_ref::<UnknownTransaction>()
+            .map(|unknown| unknown.0),
+        Some(tx_id)
+    );
+    assert!(start.elapsed() < config.timeout);
+    Ok(())
+}